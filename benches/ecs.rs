@@ -0,0 +1,72 @@
+#![feature(test)]
+
+extern crate test;
+
+use flywheel::ecs::{Entity, Manager};
+use test::{Bencher, black_box};
+
+const COUNT: usize = 10_000;
+
+#[must_use]
+fn setup() -> (Manager, Vec<(Entity, u32)>) {
+    let mut manager = Manager::default();
+    let entries = (0..)
+        .take(COUNT)
+        .map(|health| (manager.spawn_entity(), health))
+        .collect();
+    (manager, entries)
+}
+
+#[bench]
+fn add_component(bencher: &mut Bencher) {
+    bencher.iter(|| {
+        let (mut manager, entries) = setup();
+        for (owner, component) in entries {
+            let _ = manager.add_component(owner, component);
+        }
+        black_box(&mut manager);
+    });
+}
+
+#[bench]
+fn add_component_batch(bencher: &mut Bencher) {
+    bencher.iter(|| {
+        let (mut manager, entries) = setup();
+        let _ = manager.add_component_batch(&entries);
+        black_box(&mut manager);
+    });
+}
+
+#[bench]
+fn destroy_entity(bencher: &mut Bencher) {
+    bencher.iter(|| {
+        let (mut manager, entries) = setup();
+        let _ = manager.add_component_batch(&entries);
+        for (entity, _) in entries {
+            manager.destroy_entity(entity);
+        }
+        black_box(&mut manager);
+    });
+}
+
+#[bench]
+fn destroy_entities(bencher: &mut Bencher) {
+    bencher.iter(|| {
+        let (mut manager, entries) = setup();
+        let _ = manager.add_component_batch(&entries);
+        let entities = entries.iter().map(|(e, _)| *e).collect::<Vec<_>>();
+        manager.destroy_entities(&entities);
+        black_box(&mut manager);
+    });
+}
+
+#[bench]
+fn has_component(bencher: &mut Bencher) {
+    let (mut manager, entries) = setup();
+    let _ = manager.add_component_batch(&entries);
+    bencher.iter(|| {
+        for (entity, _) in &entries {
+            black_box(manager.has_component::<u32>(*entity));
+        }
+    });
+}
@@ -0,0 +1,30 @@
+#![feature(test)]
+
+extern crate test;
+
+use flywheel::{Matrix, Vector, into_rads};
+use test::{Bencher, black_box};
+
+const COUNT: usize = 10_000;
+
+#[bench]
+fn mul(bencher: &mut Bencher) {
+    let matrix = Matrix::new(into_rads(45.0), 7.0);
+    let mut vectors = vec![Vector::new(2.0, 3.0, 6.0); COUNT];
+    bencher.iter(|| {
+        for vector in &mut vectors {
+            *vector = matrix * *vector;
+        }
+        black_box(&mut vectors);
+    });
+}
+
+#[bench]
+fn transform_slice(bencher: &mut Bencher) {
+    let matrix = Matrix::new(into_rads(45.0), 7.0);
+    let mut vectors = vec![Vector::new(2.0, 3.0, 6.0); COUNT];
+    bencher.iter(|| {
+        matrix.transform_slice(&mut vectors);
+        black_box(&mut vectors);
+    });
+}
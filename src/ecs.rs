@@ -1,12 +1,34 @@
-#![allow(clippy::missing_errors_doc, dead_code, missing_docs)]
+#![allow(
+    clippy::missing_errors_doc,
+    clippy::result_unit_err,
+    dead_code,
+    missing_docs
+)]
 
-pub use self::{entity::Entity, manager::Manager, world::World};
+pub use self::{
+    archetype::Archetype,
+    commands::{Command, Commands},
+    entity::Entity,
+    entity_stats::EntityStats,
+    manager::{AddError, EntityDead, Manager},
+    pool_view::{PoolView, PoolViewMut},
+    system::{ReadOnlySystemCallback, System, SystemBuilder, SystemCallback},
+    timer::{DeltaTime, Timer, tick_timers},
+    world::World,
+};
 
 mod archetype;
+mod commands;
 mod component_manager;
 mod component_pool;
 mod entity;
+mod entity_data;
 mod entity_manager;
+mod entity_stats;
 mod manager;
+mod noop_hasher;
+mod pool_view;
+mod resource_manager;
 mod system;
+mod timer;
 mod world;
@@ -1,5 +1,8 @@
+use std::iter;
+
 type Segment = usize;
 
+#[derive(Clone)]
 pub struct Archetype {
     count: usize,
     segments: Vec<Segment>,
@@ -39,6 +42,20 @@ impl Archetype {
                 != 0
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        ids(self.segments.iter().copied())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.segments.iter().map(|s| s.count_ones() as usize).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.segments.iter().all(|s| *s == 0)
+    }
+
     #[must_use]
     pub fn has_common_with(&self, other: &Self) -> bool {
         self.segments
@@ -47,6 +64,31 @@ impl Archetype {
             .any(|(s, o)| s & o != 0)
     }
 
+    #[must_use]
+    pub fn common_with(&self, other: &Self) -> Vec<usize> {
+        ids(self
+            .segments
+            .iter()
+            .zip(&other.segments)
+            .map(|(s, o)| s & o))
+        .collect()
+    }
+
+    #[must_use]
+    pub fn added_since(&self, other: &Self) -> Vec<usize> {
+        ids(self
+            .segments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s & !other.segments.get(i).unwrap_or(&0)))
+        .collect()
+    }
+
+    #[must_use]
+    pub fn removed_since(&self, other: &Self) -> Vec<usize> {
+        other.added_since(self)
+    }
+
     #[must_use]
     pub fn is_subset_of(&self, other: &Self) -> bool {
         self.count <= other.count
@@ -83,6 +125,24 @@ impl Archetype {
     }
 }
 
+fn ids(segments: impl Iterator<Item = Segment>) -> impl Iterator<Item = usize> {
+    segments.enumerate().flat_map(|(i, mut segment)| {
+        iter::from_fn(move || {
+            (segment != 0).then(|| {
+                let bit = segment.trailing_zeros() as usize;
+                segment &= segment - 1;
+                i * Segment::BITS as usize + bit
+            })
+        })
+    })
+}
+
+impl Default for Archetype {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PartialEq for Archetype {
     fn eq(&self, other: &Self) -> bool {
         let len = self.segments.len().min(other.segments.len());
@@ -129,6 +189,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iter() {
+        let mut archetype = setup();
+        assert!(archetype.iter().eq([0, 1]));
+        for id in [
+            Segment::BITS as usize - 1,
+            Segment::BITS as usize,
+            Segment::BITS as usize * 3 + 5,
+        ] {
+            assert!(!archetype.insert(id));
+        }
+        assert!(archetype.remove(1));
+        assert!(archetype.iter().eq([
+            0,
+            Segment::BITS as usize - 1,
+            Segment::BITS as usize,
+            Segment::BITS as usize * 3 + 5,
+        ]));
+        archetype.clear();
+        assert_eq!(archetype.iter().count(), 0);
+    }
+
+    #[test]
+    fn len() {
+        let mut archetype = Archetype::new();
+        assert_eq!(archetype.len(), 0);
+        assert!(archetype.is_empty());
+        assert!(!archetype.insert(Segment::BITS as usize));
+        assert_eq!(archetype.len(), 1);
+        assert!(!archetype.is_empty());
+        for id in [0, 1, Segment::BITS as usize - 1, Segment::BITS as usize * 2]
+        {
+            assert!(!archetype.insert(id));
+        }
+        assert_eq!(archetype.len(), 5);
+        assert!(archetype.remove(Segment::BITS as usize));
+        assert_eq!(archetype.len(), 4);
+        archetype.clear();
+        assert_eq!(archetype.len(), 0);
+        assert!(archetype.is_empty());
+    }
+
     #[test]
     fn has_common_with() {
         let mut archetype = setup();
@@ -143,6 +245,56 @@ mod tests {
         assert!(other.has_common_with(&archetype));
     }
 
+    #[test]
+    fn common_with() {
+        let mut archetype = setup();
+        let mut other = setup();
+        assert_eq!(archetype.common_with(&other), [0, 1]);
+        assert!(!archetype.insert(Segment::BITS as usize + 1));
+        assert!(!other.insert(Segment::BITS as usize + 1));
+        assert!(other.remove(0));
+        assert_eq!(
+            archetype.common_with(&other),
+            [1, Segment::BITS as usize + 1]
+        );
+        assert_eq!(
+            other.common_with(&archetype),
+            [1, Segment::BITS as usize + 1]
+        );
+        archetype.clear();
+        assert!(archetype.common_with(&other).is_empty());
+    }
+
+    #[test]
+    fn added_since() {
+        let snapshot = setup();
+        let mut archetype = snapshot.clone();
+        assert!(archetype.added_since(&snapshot).is_empty());
+        assert!(!archetype.insert(2));
+        assert!(!archetype.insert(Segment::BITS as usize));
+        assert!(archetype.remove(0));
+        assert_eq!(
+            archetype.added_since(&snapshot),
+            [2, Segment::BITS as usize]
+        );
+        assert_eq!(snapshot.added_since(&archetype), [0]);
+    }
+
+    #[test]
+    fn removed_since() {
+        let snapshot = setup();
+        let mut archetype = snapshot.clone();
+        assert!(archetype.removed_since(&snapshot).is_empty());
+        assert!(!archetype.insert(Segment::BITS as usize));
+        assert!(archetype.remove(0));
+        assert!(archetype.remove(1));
+        assert_eq!(archetype.removed_since(&snapshot), [0, 1]);
+        assert_eq!(
+            snapshot.removed_since(&archetype),
+            [Segment::BITS as usize]
+        );
+    }
+
     #[test]
     fn is_subset_of() {
         let mut archetype = setup();
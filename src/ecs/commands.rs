@@ -0,0 +1,78 @@
+use super::{Entity, Manager};
+
+pub trait Command = FnOnce(&mut Manager);
+
+pub struct Commands {
+    queue: Vec<Box<dyn Command>>,
+}
+
+impl Commands {
+    #[must_use]
+    pub(super) const fn new() -> Self {
+        Self { queue: Vec::new() }
+    }
+
+    pub fn push<F: Command + 'static>(&mut self, command: F) {
+        self.queue.push(Box::new(command));
+    }
+
+    pub fn add_component<T: 'static>(&mut self, owner: Entity, component: T) {
+        self.push(move |manager| {
+            let _ = manager.add_component(owner, component);
+        });
+    }
+
+    pub fn remove_component<T: 'static>(&mut self, owner: Entity) {
+        self.push(move |manager| manager.remove_component::<T>(owner));
+    }
+
+    pub fn destroy_entity(&mut self, entity: Entity) {
+        self.push(move |manager| manager.destroy_entity(entity));
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub(super) fn apply(self, manager: &mut Manager) {
+        for command in self.queue {
+            command(manager);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Health(u8);
+
+    #[test]
+    fn apply() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        let mut commands = Commands::new();
+        assert!(commands.is_empty());
+        commands.add_component(entity0, Health(1));
+        commands.remove_component::<Health>(entity1);
+        commands.push(move |manager| {
+            let _ = manager.add_component(entity1, Health(3));
+        });
+        commands.destroy_entity(entity0);
+        assert_eq!(commands.len(), 4);
+        assert!(manager.component::<Health>(entity0).is_none());
+        assert_eq!(manager.component::<Health>(entity1), Some(&Health(2)));
+        commands.apply(&mut manager);
+        assert!(!manager.is_entity_alive(entity0));
+        assert_eq!(manager.component::<Health>(entity1), Some(&Health(3)));
+    }
+}
@@ -1,7 +1,9 @@
 use std::{
-    any::{Any, TypeId},
+    any::{self, Any, TypeId},
+    cell::Cell,
     collections::HashMap,
     hash::BuildHasherDefault,
+    mem,
 };
 
 use super::{
@@ -13,6 +15,9 @@ use super::{
 pub struct ComponentManager {
     ids: HashMap<TypeId, usize, BuildHasherDefault<NoopHasher>>,
     pools: Vec<Box<dyn AnyComponentPool>>,
+    type_names: Vec<&'static str>,
+    capacity: usize,
+    cache: Cell<Option<(TypeId, usize)>>,
 }
 
 impl ComponentManager {
@@ -21,23 +26,60 @@ impl ComponentManager {
         Self {
             ids: HashMap::default(),
             pools: Vec::new(),
+            type_names: Vec::new(),
+            capacity: 0,
+            cache: Cell::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Self::new()
         }
     }
 
     #[must_use]
     pub fn id<T: 'static>(&self) -> Option<usize> {
-        self.ids.get(&TypeId::of::<T>()).copied()
+        let type_id = TypeId::of::<T>();
+        if let Some((cached, id)) = self.cache.get()
+            && cached == type_id
+        {
+            return Some(id);
+        }
+        let id = *self.ids.get(&type_id)?;
+        self.cache.set(Some((type_id, id)));
+        Some(id)
     }
 
     #[must_use]
     pub fn register<T: 'static>(&mut self) -> usize {
         *self.ids.entry(TypeId::of::<T>()).or_insert_with(|| {
             let id = self.pools.len();
-            self.pools.push(Box::new(ComponentPool::<T>::new()));
+            self.pools.push(Box::new(ComponentPool::<T>::with_capacity(
+                self.capacity,
+            )));
+            self.type_names.push(any::type_name::<T>());
             id
         })
     }
 
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.pools.len()
+    }
+
+    #[must_use]
+    pub fn type_name(&self, id: usize) -> Option<&'static str> {
+        self.type_names.get(id).copied()
+    }
+
+    #[must_use]
+    pub fn registered_types(&self) -> Vec<(usize, &'static str)> {
+        self.type_names.iter().copied().enumerate().collect()
+    }
+
     pub fn insert<T: 'static>(
         &mut self,
         owner: Entity,
@@ -52,15 +94,51 @@ impl ComponentManager {
             self.pools.push(Box::new(ComponentPool::new_with_initial(
                 owner, component,
             )));
+            self.type_names.push(any::type_name::<T>());
             None
         }
     }
 
+    pub fn insert_batch<T: Clone + 'static>(
+        &mut self,
+        entries: &[(Entity, T)],
+    ) {
+        let _ = self.register::<T>();
+        self.pool_mut().unwrap().insert_batch(entries);
+    }
+
     #[must_use]
     pub fn has<T: 'static>(&self, owner: Entity) -> bool {
         self.pool::<T>().is_some_and(|p| p.has(owner))
     }
 
+    #[must_use]
+    pub fn has_by_id(&self, id: usize, owner: Entity) -> bool {
+        self.pools.get(id).is_some_and(|p| p.has(owner))
+    }
+
+    #[must_use]
+    pub fn get_by_id(&self, id: usize, owner: Entity) -> Option<&dyn Any> {
+        self.pools.get(id)?.get_any(owner)
+    }
+
+    #[must_use]
+    pub fn get_mut_by_id(
+        &mut self,
+        id: usize,
+        owner: Entity,
+    ) -> Option<&mut dyn Any> {
+        self.pools.get_mut(id)?.get_any_mut(owner)
+    }
+
+    pub fn remove_by_id(
+        &mut self,
+        id: usize,
+        owner: Entity,
+    ) -> Option<Box<dyn Any>> {
+        self.pools.get_mut(id)?.remove_any(owner)
+    }
+
     #[must_use]
     pub fn get<T: 'static>(&self, owner: Entity) -> Option<&T> {
         self.pool()?.get(owner)
@@ -81,10 +159,95 @@ impl ComponentManager {
         self.pool_mut().map_or(&mut [], |p| p.all_mut())
     }
 
+    pub fn iter<T: 'static>(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.pool().into_iter().flat_map(ComponentPool::iter)
+    }
+
+    pub fn iter_mut<T: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, &mut T)> {
+        self.pool_mut()
+            .into_iter()
+            .flat_map(ComponentPool::iter_mut)
+    }
+
+    pub fn swap<T: 'static>(&mut self, a: Entity, b: Entity) -> bool {
+        self.pool_mut::<T>().is_some_and(|p| p.swap(a, b))
+    }
+
+    #[must_use]
+    pub fn split_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> (&ComponentPool<A>, &mut ComponentPool<B>) {
+        assert_ne!(TypeId::of::<A>(), TypeId::of::<B>());
+        let a = self.register::<A>();
+        let b = self.register::<B>();
+        let [pool_a, pool_b] = self.pools.get_disjoint_mut([a, b]).unwrap();
+        let pool_a = (pool_a.as_mut() as &mut dyn Any)
+            .downcast_mut::<ComponentPool<A>>()
+            .unwrap();
+        let pool_b = (pool_b.as_mut() as &mut dyn Any)
+            .downcast_mut::<ComponentPool<B>>()
+            .unwrap();
+        (pool_a, pool_b)
+    }
+
+    pub fn zip_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, &mut A, &mut B)> {
+        assert_ne!(TypeId::of::<A>(), TypeId::of::<B>());
+        let (mut forward, mut backward) = (None, None);
+        if let (Some(a), Some(b)) = (self.id::<A>(), self.id::<B>()) {
+            let [pool_a, pool_b] = self.pools.get_disjoint_mut([a, b]).unwrap();
+            let pool_a = (pool_a.as_mut() as &mut dyn Any)
+                .downcast_mut::<ComponentPool<A>>()
+                .unwrap();
+            let pool_b = (pool_b.as_mut() as &mut dyn Any)
+                .downcast_mut::<ComponentPool<B>>()
+                .unwrap();
+            if pool_a.all().len() <= pool_b.all().len() {
+                forward = Some(pool_a.join_mut(pool_b));
+            } else {
+                backward = Some(pool_b.join_mut(pool_a));
+            }
+        }
+        forward.into_iter().flatten().chain(
+            backward
+                .into_iter()
+                .flatten()
+                .map(|(owner, b, a)| (owner, a, b)),
+        )
+    }
+
     pub fn remove<T: 'static>(&mut self, owner: Entity) -> Option<T> {
         self.pool_mut()?.remove(owner)
     }
 
+    pub fn remove_stable<T: 'static>(&mut self, owner: Entity) -> Option<T> {
+        self.pool_mut()?.remove_stable(owner)
+    }
+
+    pub fn retain<T: 'static, F: FnMut(Entity, &mut T) -> bool>(
+        &mut self,
+        predicate: F,
+    ) -> Vec<Entity> {
+        self.pool_mut()
+            .map_or_else(Vec::new, |p| p.retain(predicate))
+    }
+
+    pub fn sort_by_key<T: 'static, K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        key: F,
+    ) {
+        if let Some(pool) = self.pool_mut() {
+            pool.sort_by_key(key);
+        }
+    }
+
+    pub fn take<T: 'static>(&mut self) -> Option<ComponentPool<T>> {
+        Some(mem::replace(self.pool_mut()?, ComponentPool::new()))
+    }
+
     #[must_use]
     pub fn owners<T: 'static>(&self) -> &[Entity] {
         self.pool::<T>().map_or(&[], |p| p.owners())
@@ -96,6 +259,12 @@ impl ComponentManager {
         }
     }
 
+    pub fn destroy_batch(&mut self, owners: &[Entity]) {
+        for pool in &mut self.pools {
+            pool.destroy_batch(owners);
+        }
+    }
+
     pub fn clear(&mut self) {
         for pool in &mut self.pools {
             pool.clear();
@@ -126,13 +295,13 @@ impl ComponentManager {
 mod tests {
     use super::*;
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
     struct Health(u8);
 
     #[derive(Debug, Eq, PartialEq)]
     struct Damage(u8);
 
-    #[derive(Debug, Eq, PartialEq)]
+    #[derive(Debug, Eq, PartialEq, Clone)]
     struct Shield(u8);
 
     const ENTITY0: Entity = Entity::new(0);
@@ -166,6 +335,42 @@ mod tests {
         assert!(component_manager.id::<Shield>().is_none());
     }
 
+    #[test]
+    fn with_capacity() {
+        const CAPACITY: usize = 64;
+        let mut component_manager = ComponentManager::with_capacity(CAPACITY);
+        assert_eq!(component_manager.register::<Health>(), 0);
+        let capacity = component_manager.pool::<Health>().unwrap().capacity();
+        assert!(capacity >= CAPACITY);
+        for id in 0..CAPACITY {
+            assert!(
+                component_manager
+                    .insert(Entity::new(id), ENTITY0_HEALTH)
+                    .is_none()
+            );
+        }
+        assert_eq!(
+            component_manager.pool::<Health>().unwrap().capacity(),
+            capacity
+        );
+    }
+
+    #[test]
+    fn id_cache() {
+        let mut component_manager = setup();
+        for _ in 0..2 {
+            assert_eq!(component_manager.id::<Health>(), Some(0));
+            assert_eq!(component_manager.id::<Health>(), Some(0));
+            assert_eq!(component_manager.id::<Damage>(), Some(1));
+            assert!(component_manager.id::<Shield>().is_none());
+        }
+        assert_eq!(component_manager.register::<Shield>(), 2);
+        assert_eq!(component_manager.id::<Shield>(), Some(2));
+        assert_eq!(component_manager.id::<Health>(), Some(0));
+        assert!(component_manager.has::<Health>(ENTITY0));
+        assert!(!component_manager.has::<Shield>(ENTITY0));
+    }
+
     #[test]
     fn register() {
         let mut component_manager = setup();
@@ -174,6 +379,43 @@ mod tests {
         assert_eq!(component_manager.register::<Shield>(), 2);
     }
 
+    #[test]
+    fn count() {
+        let mut component_manager = setup();
+        assert_eq!(component_manager.count(), 2);
+        assert_eq!(component_manager.register::<Shield>(), 2);
+        assert_eq!(component_manager.count(), 3);
+    }
+
+    #[test]
+    fn type_name() {
+        let component_manager = setup();
+        assert_eq!(
+            component_manager.type_name(0),
+            Some(any::type_name::<Health>())
+        );
+        assert_eq!(
+            component_manager.type_name(1),
+            Some(any::type_name::<Damage>())
+        );
+        assert!(component_manager.type_name(2).is_none());
+    }
+
+    #[test]
+    fn registered_types() {
+        let mut component_manager = setup();
+        assert_eq!(component_manager.register::<Shield>(), 2);
+        assert_eq!(
+            component_manager.registered_types(),
+            [
+                (0, any::type_name::<Health>()),
+                (1, any::type_name::<Damage>()),
+                (2, any::type_name::<Shield>()),
+            ]
+        );
+        assert!(ComponentManager::new().registered_types().is_empty());
+    }
+
     #[test]
     fn insert() {
         let mut component_manager = setup();
@@ -214,6 +456,24 @@ mod tests {
         assert_eq!(component_manager.get(ENTITY2), Some(&ENTITY2_SHIELD));
     }
 
+    #[test]
+    fn insert_batch() {
+        let entries = [(ENTITY2, ENTITY2_HEALTH), (ENTITY0, Health(7))];
+        let mut batched = setup();
+        batched.insert_batch(&entries);
+        batched.insert_batch(&[(ENTITY1, Shield(1))]);
+        let mut sequential = setup();
+        for (owner, component) in entries.clone() {
+            let _ = sequential.insert(owner, component);
+        }
+        let _ = sequential.insert(ENTITY1, Shield(1));
+        assert_eq!(batched.all::<Health>(), sequential.all::<Health>());
+        assert_eq!(batched.owners::<Health>(), sequential.owners::<Health>());
+        assert_eq!(batched.all::<Shield>(), sequential.all::<Shield>());
+        assert_eq!(batched.id::<Shield>(), sequential.id::<Shield>());
+        assert_eq!(batched.type_name(2), sequential.type_name(2));
+    }
+
     #[test]
     fn has() {
         let component_manager = setup();
@@ -228,6 +488,47 @@ mod tests {
         assert!(!component_manager.has::<Shield>(ENTITY2));
     }
 
+    #[test]
+    fn by_id() {
+        let mut component_manager = setup();
+        let health = component_manager.id::<Health>().unwrap();
+        let damage = component_manager.id::<Damage>().unwrap();
+        assert!(component_manager.has_by_id(health, ENTITY0));
+        assert!(component_manager.has_by_id(damage, ENTITY1));
+        assert!(!component_manager.has_by_id(health, ENTITY2));
+        assert!(!component_manager.has_by_id(2, ENTITY0));
+        assert_eq!(
+            component_manager
+                .get_by_id(health, ENTITY1)
+                .unwrap()
+                .downcast_ref(),
+            Some(&ENTITY1_HEALTH)
+        );
+        assert!(component_manager.get_by_id(2, ENTITY0).is_none());
+        component_manager
+            .get_mut_by_id(damage, ENTITY0)
+            .unwrap()
+            .downcast_mut::<Damage>()
+            .unwrap()
+            .0 += 1;
+        assert_eq!(
+            component_manager.get(ENTITY0),
+            Some(&Damage(ENTITY0_DAMAGE.0 + 1))
+        );
+        assert_eq!(
+            *component_manager
+                .remove_by_id(health, ENTITY0)
+                .unwrap()
+                .downcast::<Health>()
+                .unwrap(),
+            ENTITY0_HEALTH
+        );
+        assert!(!component_manager.has_by_id(health, ENTITY0));
+        assert!(component_manager.remove_by_id(health, ENTITY0).is_none());
+        assert!(component_manager.remove_by_id(2, ENTITY0).is_none());
+        assert!(component_manager.has_by_id(damage, ENTITY0));
+    }
+
     #[test]
     fn get() {
         let component_manager = setup();
@@ -274,6 +575,39 @@ mod tests {
         assert!(component_manager.all::<Shield>().is_empty());
     }
 
+    #[test]
+    fn iter() {
+        let component_manager = setup();
+        assert!(
+            component_manager
+                .iter::<Health>()
+                .eq([(ENTITY0, &ENTITY0_HEALTH), (ENTITY1, &ENTITY1_HEALTH)])
+        );
+        assert_eq!(component_manager.iter::<Shield>().count(), 0);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut component_manager = setup();
+        for (owner, health) in component_manager.iter_mut::<Health>() {
+            if owner == ENTITY0 {
+                assert_eq!(*health, ENTITY0_HEALTH);
+            } else {
+                assert_eq!(*health, ENTITY1_HEALTH);
+            }
+            health.0 += 1;
+        }
+        assert_eq!(
+            component_manager.get(ENTITY0),
+            Some(&Health(ENTITY0_HEALTH.0 + 1))
+        );
+        assert_eq!(
+            component_manager.get(ENTITY1),
+            Some(&Health(ENTITY1_HEALTH.0 + 1))
+        );
+        assert_eq!(component_manager.iter_mut::<Shield>().count(), 0);
+    }
+
     #[test]
     fn all_mut() {
         let mut component_manager = setup();
@@ -288,6 +622,73 @@ mod tests {
         assert!(component_manager.all_mut::<Shield>().is_empty());
     }
 
+    #[test]
+    fn swap() {
+        let mut component_manager = setup();
+        assert!(component_manager.swap::<Health>(ENTITY0, ENTITY1));
+        assert_eq!(component_manager.get(ENTITY0), Some(&ENTITY1_HEALTH));
+        assert_eq!(component_manager.get(ENTITY1), Some(&ENTITY0_HEALTH));
+        assert_eq!(component_manager.get(ENTITY0), Some(&ENTITY0_DAMAGE));
+        assert!(!component_manager.swap::<Damage>(ENTITY0, ENTITY2));
+        assert!(!component_manager.swap::<Shield>(ENTITY0, ENTITY1));
+    }
+
+    #[test]
+    fn zip_mut() {
+        let mut component_manager = setup();
+        assert!(component_manager.insert(ENTITY2, ENTITY2_HEALTH).is_none());
+        assert!(component_manager.insert(ENTITY1, ENTITY1_SHIELD).is_none());
+        assert!(component_manager.insert(ENTITY2, ENTITY2_SHIELD).is_none());
+        for (_, health, damage) in component_manager.zip_mut::<Health, Damage>()
+        {
+            health.0 += damage.0;
+            damage.0 = 0;
+        }
+        assert_eq!(
+            component_manager.all::<Health>(),
+            [Health(3), Health(9), ENTITY2_HEALTH]
+        );
+        assert_eq!(component_manager.all::<Damage>(), [Damage(0), Damage(0)]);
+        assert_eq!(
+            component_manager
+                .zip_mut::<Shield, Damage>()
+                .map(|(owner, _, _)| owner)
+                .collect::<Vec<_>>(),
+            [ENTITY1]
+        );
+        assert!(
+            component_manager
+                .zip_mut::<Health, ComponentManager>()
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn zip_mut_same() {
+        let _ = setup().zip_mut::<Health, Health>().count();
+    }
+
+    #[test]
+    fn split_mut() {
+        let mut component_manager = setup();
+        let (health, damage) = component_manager.split_mut::<Health, Damage>();
+        for (owner, damage) in damage.iter_mut() {
+            damage.0 += health.get(owner).unwrap().0;
+        }
+        assert_eq!(component_manager.all::<Damage>(), [Damage(3), Damage(9)]);
+        let (_, shield) = component_manager.split_mut::<Health, Shield>();
+        assert!(shield.all().is_empty());
+        assert_eq!(component_manager.id::<Shield>(), Some(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn split_mut_same() {
+        let _ = setup().split_mut::<Health, Health>();
+    }
+
     #[test]
     #[allow(clippy::cognitive_complexity)]
     fn remove() {
@@ -324,6 +725,66 @@ mod tests {
         assert!(component_manager.remove::<Shield>(ENTITY2).is_none());
     }
 
+    #[test]
+    fn remove_stable() {
+        let mut component_manager = setup();
+        assert!(component_manager.insert(ENTITY2, ENTITY2_HEALTH).is_none());
+        assert!(component_manager.remove_stable::<Shield>(ENTITY0).is_none());
+        assert_eq!(
+            component_manager.remove_stable(ENTITY0),
+            Some(ENTITY0_HEALTH)
+        );
+        assert!(!component_manager.has::<Health>(ENTITY0));
+        assert!(component_manager.remove_stable::<Health>(ENTITY0).is_none());
+        assert_eq!(
+            component_manager.all::<Health>(),
+            [ENTITY1_HEALTH, ENTITY2_HEALTH]
+        );
+        assert_eq!(component_manager.owners::<Health>(), [ENTITY1, ENTITY2]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut component_manager = setup();
+        assert_eq!(
+            component_manager.retain::<Health, _>(|owner, _| owner != ENTITY0),
+            [ENTITY0]
+        );
+        assert_eq!(component_manager.all::<Health>(), [ENTITY1_HEALTH]);
+        assert_eq!(component_manager.owners::<Health>(), [ENTITY1]);
+        assert_eq!(
+            component_manager.all::<Damage>(),
+            [ENTITY0_DAMAGE, ENTITY1_DAMAGE]
+        );
+        assert!(
+            component_manager
+                .retain::<Shield, _>(|_, _| false)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn take() {
+        let mut component_manager = setup();
+        assert_eq!(
+            component_manager
+                .take::<Health>()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [(ENTITY0, ENTITY0_HEALTH), (ENTITY1, ENTITY1_HEALTH)]
+        );
+        assert!(component_manager.all::<Health>().is_empty());
+        assert!(!component_manager.has::<Health>(ENTITY0));
+        assert!(component_manager.insert(ENTITY1, ENTITY1_HEALTH).is_none());
+        assert_eq!(component_manager.owners::<Health>(), [ENTITY1]);
+        assert_eq!(
+            component_manager.all::<Damage>(),
+            [ENTITY0_DAMAGE, ENTITY1_DAMAGE]
+        );
+        assert!(component_manager.take::<Shield>().is_none());
+    }
+
     #[test]
     fn owners() {
         let component_manager = setup();
@@ -355,6 +816,19 @@ mod tests {
         component_manager.destroy(ENTITY2);
     }
 
+    #[test]
+    fn destroy_batch() {
+        let mut component_manager = setup();
+        component_manager.destroy_batch(&[ENTITY0, ENTITY2]);
+        assert!(!component_manager.has::<Health>(ENTITY0));
+        assert!(!component_manager.has::<Damage>(ENTITY0));
+        assert_eq!(component_manager.get(ENTITY1), Some(&ENTITY1_HEALTH));
+        assert_eq!(component_manager.get(ENTITY1), Some(&ENTITY1_DAMAGE));
+        component_manager.destroy_batch(&[ENTITY0, ENTITY1]);
+        assert!(component_manager.all::<Health>().is_empty());
+        assert!(component_manager.all::<Damage>().is_empty());
+    }
+
     #[test]
     fn clear() {
         let mut component_manager = setup();
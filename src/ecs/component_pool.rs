@@ -1,13 +1,31 @@
-use std::{any::Any, mem};
+use std::{
+    any::Any, collections::HashMap, hash::BuildHasherDefault, iter::Zip, mem,
+    vec::IntoIter,
+};
 
-use super::Entity;
+use super::{Entity, noop_hasher::NoopHasher};
+
+const SPARSE_LIMIT: usize = 1 << 16;
 
 pub trait AnyComponentPool: Any {
     #[must_use]
     fn owners(&self) -> &[Entity];
 
+    #[must_use]
+    fn has(&self, owner: Entity) -> bool;
+
+    #[must_use]
+    fn get_any(&self, owner: Entity) -> Option<&dyn Any>;
+
+    #[must_use]
+    fn get_any_mut(&mut self, owner: Entity) -> Option<&mut dyn Any>;
+
+    fn remove_any(&mut self, owner: Entity) -> Option<Box<dyn Any>>;
+
     fn destroy(&mut self, owner: Entity);
 
+    fn destroy_batch(&mut self, owners: &[Entity]);
+
     fn clear(&mut self);
 }
 
@@ -15,6 +33,7 @@ pub struct ComponentPool<T> {
     dense: Vec<T>,
     owners: Vec<Entity>,
     sparse: Vec<Option<usize>>,
+    overflow: HashMap<usize, usize, BuildHasherDefault<NoopHasher>>,
 }
 
 impl<T> ComponentPool<T> {
@@ -24,47 +43,76 @@ impl<T> ComponentPool<T> {
             dense: Vec::new(),
             owners: Vec::new(),
             sparse: Vec::new(),
+            overflow: HashMap::with_hasher(BuildHasherDefault::new()),
         }
     }
 
     #[must_use]
-    pub fn new_with_initial(owner: Entity, component: T) -> Self {
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            dense: vec![component],
-            owners: vec![owner],
-            sparse: vec![Some(owner.id())],
+            dense: Vec::with_capacity(capacity),
+            owners: Vec::with_capacity(capacity),
+            sparse: Vec::with_capacity(capacity),
+            overflow: HashMap::with_hasher(BuildHasherDefault::new()),
         }
     }
 
+    #[must_use]
+    pub fn new_with_initial(owner: Entity, component: T) -> Self {
+        let mut component_pool = Self::new();
+        component_pool.insert(owner, component);
+        component_pool
+    }
+
     pub fn insert(&mut self, owner: Entity, component: T) -> Option<T> {
-        if self.sparse.len() <= owner.id() {
-            self.sparse.resize(owner.id() + 1, None);
-        }
-        if let Some(index) = self.sparse[owner.id()] {
+        if let Some(index) = self.index(owner) {
             Some(mem::replace(&mut self.dense[index], component))
         } else {
-            self.sparse[owner.id()] = Some(self.dense.len());
+            self.set_index(owner, Some(self.dense.len()));
             self.dense.push(component);
             self.owners.push(owner);
             None
         }
     }
 
+    pub fn insert_batch(&mut self, entries: &[(Entity, T)])
+    where
+        T: Clone,
+    {
+        if let Some(max) = entries
+            .iter()
+            .map(|(owner, _)| owner.id())
+            .filter(|id| *id < SPARSE_LIMIT)
+            .max()
+            && self.sparse.len() <= max
+        {
+            self.sparse.resize(max + 1, None);
+        }
+        self.dense.reserve(entries.len());
+        self.owners.reserve(entries.len());
+        for (owner, component) in entries {
+            self.insert(*owner, component.clone());
+        }
+    }
+
     #[must_use]
     pub fn has(&self, owner: Entity) -> bool {
-        self.sparse.get(owner.id()).is_some_and(Option::is_some)
+        self.index(owner).is_some()
     }
 
     #[must_use]
     pub fn get(&self, owner: Entity) -> Option<&T> {
-        Some(&self.dense[(*self.sparse.get(owner.id())?)?])
+        Some(&self.dense[self.index(owner)?])
     }
 
     #[must_use]
     pub fn get_mut(&mut self, owner: Entity) -> Option<&mut T> {
-        Some(&mut self.dense[(*self.sparse.get(owner.id())?)?])
+        let index = self.index(owner)?;
+        Some(&mut self.dense[index])
     }
 
+    /// Returns the components in storage order, which changes whenever a
+    /// component is inserted or removed.
     #[must_use]
     pub fn all(&self) -> &[T] {
         &self.dense
@@ -75,19 +123,139 @@ impl<T> ComponentPool<T> {
         &mut self.dense
     }
 
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.dense.capacity()
+    }
+
+    pub fn swap(&mut self, a: Entity, b: Entity) -> bool {
+        let (Some(a), Some(b)) = (self.index(a), self.index(b)) else {
+            return false;
+        };
+        self.dense.swap(a, b);
+        true
+    }
+
+    /// Returns the owners zipped with their components, which should be
+    /// preferred over relying on the alignment of `all` and `owners`.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.owners.iter().copied().zip(&self.dense)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, &mut T)> {
+        self.owners.iter().copied().zip(&mut self.dense)
+    }
+
+    /// Returns the owners present in both pools zipped with their components
+    /// in the order of this pool, looking the other pool up through its
+    /// sparse index so that neither storage order changes.
+    pub fn join_mut<'a, U>(
+        &'a mut self,
+        other: &'a mut ComponentPool<U>,
+    ) -> impl Iterator<Item = (Entity, &'a mut T, &'a mut U)> {
+        let ComponentPool {
+            dense,
+            sparse,
+            overflow,
+            ..
+        } = other;
+        let mut others = dense.iter_mut().map(Some).collect::<Vec<_>>();
+        self.iter_mut().filter_map(move |(owner, component)| {
+            let index = lookup(sparse, overflow, owner)?;
+            Some((owner, component, others[index].take()?))
+        })
+    }
+
+    /// Removes the component of the owner by swapping the last component into
+    /// its place, which does not preserve the order of the remaining ones.
     pub fn remove(&mut self, owner: Entity) -> Option<T> {
-        let index = (*self.sparse.get(owner.id())?)?;
-        self.sparse[owner.id()] = None;
+        let index = self.index(owner)?;
+        self.set_index(owner, None);
         Some(if index == self.dense.len() - 1 {
             self.owners.pop().unwrap();
             self.dense.pop().unwrap()
         } else {
             self.owners.swap_remove(index);
-            let swapped = self.owners[index].id();
-            self.sparse[swapped] = Some(index);
+            self.set_index(self.owners[index], Some(index));
             self.dense.swap_remove(index)
         })
     }
+
+    /// Removes the component of the owner by shifting the following components
+    /// into its place, which preserves the order of the remaining ones.
+    pub fn remove_stable(&mut self, owner: Entity) -> Option<T> {
+        let index = self.index(owner)?;
+        self.set_index(owner, None);
+        self.owners.remove(index);
+        for i in index..self.owners.len() {
+            self.set_index(self.owners[i], Some(i));
+        }
+        Some(self.dense.remove(index))
+    }
+
+    /// Removes the components failing the predicate in a single pass, which
+    /// preserves the order of the remaining ones, and returns their owners.
+    pub fn retain<F: FnMut(Entity, &mut T) -> bool>(
+        &mut self,
+        mut predicate: F,
+    ) -> Vec<Entity> {
+        let mut removed = Vec::new();
+        let mut kept = 0;
+        for index in 0..self.dense.len() {
+            let owner = self.owners[index];
+            if predicate(owner, &mut self.dense[index]) {
+                self.dense.swap(kept, index);
+                self.owners.swap(kept, index);
+                self.set_index(owner, Some(kept));
+                kept += 1;
+            } else {
+                self.set_index(owner, None);
+                removed.push(owner);
+            }
+        }
+        self.dense.truncate(kept);
+        self.owners.truncate(kept);
+        removed
+    }
+
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        let mut entries = mem::take(&mut self.owners)
+            .into_iter()
+            .zip(mem::take(&mut self.dense))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(_, component)| key(component));
+        self.owners.reserve(entries.len());
+        self.dense.reserve(entries.len());
+        for (index, (owner, component)) in entries.into_iter().enumerate() {
+            self.set_index(owner, Some(index));
+            self.owners.push(owner);
+            self.dense.push(component);
+        }
+    }
+
+    #[must_use]
+    fn index(&self, owner: Entity) -> Option<usize> {
+        lookup(&self.sparse, &self.overflow, owner)
+    }
+
+    /// Sets the dense index of the owner, falling back to a hashed map above
+    /// `SPARSE_LIMIT` so that large ids cannot blow up the sparse array.
+    fn set_index(&mut self, owner: Entity, index: Option<usize>) {
+        if SPARSE_LIMIT <= owner.id() {
+            if let Some(index) = index {
+                self.overflow.insert(owner.id(), index);
+            } else {
+                self.overflow.remove(&owner.id());
+            }
+        } else if let Some(index) = index {
+            if self.sparse.len() <= owner.id() {
+                self.sparse.resize(owner.id() + 1, None);
+            }
+            self.sparse[owner.id()] = Some(index);
+        } else if let Some(slot) = self.sparse.get_mut(owner.id()) {
+            *slot = None;
+        }
+    }
 }
 
 impl<T: 'static> AnyComponentPool for ComponentPool<T> {
@@ -95,17 +263,62 @@ impl<T: 'static> AnyComponentPool for ComponentPool<T> {
         &self.owners
     }
 
+    fn has(&self, owner: Entity) -> bool {
+        Self::has(self, owner)
+    }
+
+    fn get_any(&self, owner: Entity) -> Option<&dyn Any> {
+        Some(self.get(owner)?)
+    }
+
+    fn get_any_mut(&mut self, owner: Entity) -> Option<&mut dyn Any> {
+        Some(self.get_mut(owner)?)
+    }
+
+    fn remove_any(&mut self, owner: Entity) -> Option<Box<dyn Any>> {
+        Some(Box::new(self.remove(owner)?))
+    }
+
     fn destroy(&mut self, owner: Entity) {
         self.remove(owner);
     }
 
+    fn destroy_batch(&mut self, owners: &[Entity]) {
+        for owner in owners {
+            self.remove(*owner);
+        }
+    }
+
     fn clear(&mut self) {
         self.sparse.fill(None);
+        self.overflow.clear();
         self.owners.clear();
         self.dense.clear();
     }
 }
 
+impl<T> IntoIterator for ComponentPool<T> {
+    type IntoIter = Zip<IntoIter<Entity>, IntoIter<T>>;
+    type Item = (Entity, T);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.owners.into_iter().zip(self.dense)
+    }
+}
+
+#[must_use]
+fn lookup(
+    sparse: &[Option<usize>],
+    overflow: &HashMap<usize, usize, BuildHasherDefault<NoopHasher>>,
+    owner: Entity,
+) -> Option<usize> {
+    if owner.id() < SPARSE_LIMIT {
+        *sparse.get(owner.id())?
+    } else {
+        overflow.get(&owner.id()).copied()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +326,7 @@ mod tests {
     const ENTITY0: Entity = Entity::new(0);
     const ENTITY1: Entity = Entity::new(1);
     const ENTITY2: Entity = Entity::new(2);
+    const ENTITY3: Entity = Entity::new(3);
 
     #[must_use]
     fn setup() -> ComponentPool<usize> {
@@ -142,6 +356,70 @@ mod tests {
         assert_eq!(component_pool.get(ENTITY2), Some(&ENTITY2.id()));
     }
 
+    #[test]
+    fn with_capacity() {
+        const CAPACITY: usize = 64;
+        let mut component_pool = ComponentPool::with_capacity(CAPACITY);
+        let capacity = component_pool.capacity();
+        assert!(capacity >= CAPACITY);
+        for id in (0..CAPACITY).rev() {
+            assert!(component_pool.insert(Entity::new(id), id).is_none());
+        }
+        assert_eq!(component_pool.capacity(), capacity);
+        assert_eq!(component_pool.all().len(), CAPACITY);
+        assert_eq!(component_pool.get(Entity::new(7)), Some(&7));
+    }
+
+    #[test]
+    fn new_with_initial() {
+        let component_pool =
+            ComponentPool::new_with_initial(ENTITY2, ENTITY2.id());
+        assert_eq!(component_pool.get(ENTITY2), Some(&ENTITY2.id()));
+        assert!(!component_pool.has(ENTITY0));
+        assert_eq!(component_pool.owners(), [ENTITY2]);
+    }
+
+    #[test]
+    fn large_id() {
+        const LARGE: Entity = Entity::new(usize::MAX / 2);
+        let mut component_pool = setup();
+        assert!(component_pool.insert(LARGE, LARGE.id()).is_none());
+        assert!(component_pool.sparse.len() <= SPARSE_LIMIT);
+        assert_eq!(component_pool.overflow.len(), 1);
+        assert_eq!(component_pool.get(LARGE), Some(&LARGE.id()));
+        assert_eq!(component_pool.insert(LARGE, 3), Some(LARGE.id()));
+        assert_eq!(component_pool.remove(ENTITY0), Some(ENTITY0.id()));
+        assert_eq!(component_pool.owners(), [LARGE, ENTITY1]);
+        assert_eq!(component_pool.get(LARGE), Some(&3));
+        assert_eq!(component_pool.remove_stable(LARGE), Some(3));
+        assert!(!component_pool.has(LARGE));
+        assert!(component_pool.overflow.is_empty());
+        assert_eq!(component_pool.get(ENTITY1), Some(&ENTITY1.id()));
+    }
+
+    #[test]
+    fn insert_batch() {
+        const LARGE: Entity = Entity::new(SPARSE_LIMIT);
+        let entries = [
+            (ENTITY2, ENTITY2.id()),
+            (ENTITY0, ENTITY0.id() + 3),
+            (LARGE, LARGE.id()),
+            (ENTITY3, ENTITY3.id()),
+        ];
+        let mut batched = setup();
+        batched.insert_batch(&entries);
+        let mut sequential = setup();
+        for (owner, component) in entries {
+            sequential.insert(owner, component);
+        }
+        assert_eq!(batched.all(), sequential.all());
+        assert_eq!(batched.owners(), sequential.owners());
+        assert_eq!(batched.sparse, sequential.sparse);
+        assert_eq!(batched.overflow, sequential.overflow);
+        assert_eq!(batched.get(ENTITY0), Some(&(ENTITY0.id() + 3)));
+        assert_eq!(batched.get(LARGE), Some(&LARGE.id()));
+    }
+
     #[test]
     fn has() {
         let component_pool = setup();
@@ -176,6 +454,81 @@ mod tests {
         assert_eq!(setup().all_mut(), [ENTITY0.id(), ENTITY1.id()]);
     }
 
+    #[test]
+    fn swap() {
+        let mut component_pool = setup();
+        assert!(component_pool.swap(ENTITY0, ENTITY1));
+        assert_eq!(component_pool.get(ENTITY0), Some(&ENTITY1.id()));
+        assert_eq!(component_pool.get(ENTITY1), Some(&ENTITY0.id()));
+        assert_eq!(component_pool.owners(), [ENTITY0, ENTITY1]);
+        assert!(component_pool.swap(ENTITY0, ENTITY0));
+        assert_eq!(component_pool.get(ENTITY0), Some(&ENTITY1.id()));
+        assert!(!component_pool.swap(ENTITY0, ENTITY2));
+        assert!(!component_pool.swap(ENTITY2, ENTITY1));
+        assert_eq!(component_pool.get(ENTITY0), Some(&ENTITY1.id()));
+        assert_eq!(component_pool.get(ENTITY1), Some(&ENTITY0.id()));
+    }
+
+    #[test]
+    fn iter() {
+        let component_pool = setup();
+        let mut owners = Vec::new();
+        for (owner, component) in component_pool.iter() {
+            assert_eq!(*component, owner.id());
+            owners.push(owner);
+        }
+        assert_eq!(owners, [ENTITY0, ENTITY1]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut component_pool = setup();
+        for (owner, component) in component_pool.iter_mut() {
+            assert_eq!(*component, owner.id());
+            *component += 3;
+        }
+        assert_eq!(component_pool.get(ENTITY0), Some(&(ENTITY0.id() + 3)));
+        assert_eq!(component_pool.get(ENTITY1), Some(&(ENTITY1.id() + 3)));
+    }
+
+    #[test]
+    fn join_mut() {
+        let mut component_pool = setup();
+        assert!(component_pool.insert(ENTITY3, ENTITY3.id()).is_none());
+        let mut other = ComponentPool::new_with_initial(ENTITY2, 'c');
+        assert!(other.insert(ENTITY3, 'd').is_none());
+        assert!(other.insert(ENTITY1, 'b').is_none());
+        let mut joined = Vec::new();
+        for (owner, component, other) in component_pool.join_mut(&mut other) {
+            *component += 3;
+            *other = other.to_ascii_uppercase();
+            joined.push(owner);
+        }
+        assert_eq!(joined, [ENTITY1, ENTITY3]);
+        assert_eq!(component_pool.all(), [0, 4, 6]);
+        assert_eq!(other.owners(), [ENTITY2, ENTITY3, ENTITY1]);
+        assert_eq!(other.all(), ['c', 'D', 'B']);
+        assert_eq!(other.get(ENTITY2), Some(&'c'));
+        assert_eq!(other.get(ENTITY3), Some(&'D'));
+        assert_eq!(
+            ComponentPool::<char>::new()
+                .join_mut(&mut component_pool)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut component_pool = setup();
+        assert!(component_pool.insert(ENTITY3, ENTITY3.id()).is_none());
+        assert!(component_pool.remove(ENTITY0).is_some());
+        let mut entries = component_pool.into_iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(owner, _)| owner.id());
+        assert_eq!(entries, [(ENTITY1, ENTITY1.id()), (ENTITY3, ENTITY3.id())]);
+        assert_eq!(ComponentPool::<usize>::new().into_iter().count(), 0);
+    }
+
     #[test]
     fn remove() {
         let mut component_pool = setup();
@@ -203,11 +556,107 @@ mod tests {
         assert!(component_pool.remove(ENTITY2).is_none());
     }
 
+    #[test]
+    fn remove_stable() {
+        let mut component_pool = setup();
+        assert!(component_pool.remove_stable(ENTITY2).is_none());
+        assert!(component_pool.insert(ENTITY2, ENTITY2.id()).is_none());
+        assert_eq!(component_pool.remove_stable(ENTITY0), Some(ENTITY0.id()));
+        assert!(!component_pool.has(ENTITY0));
+        assert!(component_pool.remove_stable(ENTITY0).is_none());
+        assert_eq!(component_pool.all(), [ENTITY1.id(), ENTITY2.id()]);
+        assert_eq!(component_pool.owners(), [ENTITY1, ENTITY2]);
+        assert_eq!(component_pool.get(ENTITY1), Some(&ENTITY1.id()));
+        assert_eq!(component_pool.get(ENTITY2), Some(&ENTITY2.id()));
+
+        let mut component_pool = setup();
+        assert!(component_pool.insert(ENTITY2, ENTITY2.id()).is_none());
+        assert_eq!(component_pool.remove(ENTITY0), Some(ENTITY0.id()));
+        assert_eq!(component_pool.all(), [ENTITY2.id(), ENTITY1.id()]);
+        assert_eq!(component_pool.owners(), [ENTITY2, ENTITY1]);
+    }
+
+    #[test]
+    fn sort_by_key() {
+        const LARGE: Entity = Entity::new(SPARSE_LIMIT + 1);
+        let mut component_pool = setup();
+        assert!(component_pool.insert(ENTITY3, 1).is_none());
+        assert!(component_pool.insert(LARGE, 0).is_none());
+        component_pool.sort_by_key(|component| *component);
+        assert!(component_pool.iter().eq([
+            (ENTITY0, &0),
+            (LARGE, &0),
+            (ENTITY1, &1),
+            (ENTITY3, &1)
+        ]));
+        component_pool.sort_by_key(|component| usize::MAX - component);
+        assert_eq!(component_pool.owners(), [ENTITY1, ENTITY3, ENTITY0, LARGE]);
+        for (owner, component) in
+            [(ENTITY0, 0), (ENTITY1, 1), (ENTITY3, 1), (LARGE, 0)]
+        {
+            assert!(component_pool.has(owner));
+            assert_eq!(component_pool.get(owner), Some(&component));
+        }
+        assert!(component_pool.remove(ENTITY3).is_some());
+        assert_eq!(component_pool.owners(), [ENTITY1, LARGE, ENTITY0]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut component_pool = setup();
+        assert!(component_pool.insert(ENTITY2, ENTITY2.id()).is_none());
+        assert!(component_pool.insert(ENTITY3, ENTITY3.id()).is_none());
+        assert_eq!(
+            component_pool.retain(|owner, component| {
+                *component += 3;
+                owner.id() % 2 == 1
+            }),
+            [ENTITY0, ENTITY2]
+        );
+        assert_eq!(component_pool.all(), [ENTITY1.id() + 3, ENTITY3.id() + 3]);
+        assert_eq!(component_pool.owners(), [ENTITY1, ENTITY3]);
+        assert!(!component_pool.has(ENTITY0));
+        assert!(!component_pool.has(ENTITY2));
+        assert_eq!(component_pool.get(ENTITY1), Some(&(ENTITY1.id() + 3)));
+        assert_eq!(component_pool.get(ENTITY3), Some(&(ENTITY3.id() + 3)));
+        assert!(component_pool.retain(|_, _| true).is_empty());
+        assert_eq!(component_pool.retain(|_, _| false), [ENTITY1, ENTITY3]);
+        assert!(component_pool.all().is_empty());
+    }
+
     #[test]
     fn owners() {
         assert_eq!(setup().owners(), [ENTITY0, ENTITY1]);
     }
 
+    #[test]
+    fn any() {
+        let mut component_pool: Box<dyn AnyComponentPool> = Box::new(setup());
+        assert!(component_pool.has(ENTITY0));
+        assert!(!component_pool.has(ENTITY2));
+        assert_eq!(
+            component_pool.get_any(ENTITY1).unwrap().downcast_ref(),
+            Some(&ENTITY1.id())
+        );
+        assert!(component_pool.get_any(ENTITY2).is_none());
+        *component_pool
+            .get_any_mut(ENTITY1)
+            .unwrap()
+            .downcast_mut::<usize>()
+            .unwrap() += 3;
+        assert_eq!(
+            *component_pool
+                .remove_any(ENTITY1)
+                .unwrap()
+                .downcast::<usize>()
+                .unwrap(),
+            ENTITY1.id() + 3
+        );
+        assert!(!component_pool.has(ENTITY1));
+        assert!(component_pool.remove_any(ENTITY1).is_none());
+        assert_eq!(component_pool.owners(), [ENTITY0]);
+    }
+
     #[test]
     fn destroy() {
         let mut component_pool = setup();
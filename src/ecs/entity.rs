@@ -14,6 +14,21 @@ impl Entity {
     pub(super) const fn id(self) -> usize {
         self.0
     }
+
+    /// Reconstructs an `Entity` from its raw id, e.g. when deserializing.
+    ///
+    /// This is an advanced API: the resulting `Entity` is not guaranteed to
+    /// be alive, and entities should normally be spawned through a `Manager`.
+    #[must_use]
+    pub const fn from_raw(id: usize) -> Self {
+        Self::new(id)
+    }
+
+    /// Returns the raw id of the `Entity`, e.g. when serializing.
+    #[must_use]
+    pub const fn to_raw(self) -> usize {
+        self.id()
+    }
 }
 
 impl Display for Entity {
@@ -34,6 +49,12 @@ mod tests {
         assert_eq!(ENTITY.id(), ID);
     }
 
+    #[test]
+    fn from_raw_to_raw() {
+        assert_eq!(ENTITY.to_raw(), ID);
+        assert_eq!(Entity::from_raw(ENTITY.to_raw()), ENTITY);
+    }
+
     #[test]
     fn fmt() {
         assert_eq!(format!("{ENTITY}"), format!("{ID}"));
@@ -3,6 +3,7 @@ use super::{Entity, archetype::Archetype};
 pub(super) struct EntityData {
     owner: Entity,
     archetype: Archetype,
+    is_enabled: bool,
     parent: Option<Entity>,
     sparse: Vec<Option<usize>>,
     dense: Vec<Entity>,
@@ -14,6 +15,7 @@ impl EntityData {
         Self {
             owner,
             archetype: Archetype::new(),
+            is_enabled: true,
             parent: None,
             sparse: Vec::new(),
             dense: Vec::new(),
@@ -35,6 +37,15 @@ impl EntityData {
         &mut self.archetype
     }
 
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    pub const fn set_enabled(&mut self, value: bool) {
+        self.is_enabled = value;
+    }
+
     #[must_use]
     pub const fn parent(&self) -> Option<Entity> {
         self.parent
@@ -93,6 +104,7 @@ impl EntityData {
     pub fn clear(&mut self) {
         self.parent = None;
         self.archetype.clear();
+        self.is_enabled = true;
         self.sparse.clear();
         self.dense.clear();
     }
@@ -133,6 +145,16 @@ mod tests {
         assert!(setup().archetype_mut().has(0));
     }
 
+    #[test]
+    fn set_enabled() {
+        let mut entity_data = setup();
+        assert!(entity_data.is_enabled());
+        entity_data.set_enabled(false);
+        assert!(!entity_data.is_enabled());
+        entity_data.clear();
+        assert!(entity_data.is_enabled());
+    }
+
     #[test]
     fn parent() {
         assert_eq!(setup().parent(), Some(PARENT1));
@@ -1,8 +1,14 @@
-use super::{Entity, entity_data::EntityData};
+use super::{
+    Entity, EntityStats, archetype::Archetype, entity_data::EntityData,
+};
 
 pub struct EntityManager {
     sparse: Vec<Option<EntityData>>,
     destroyed: Vec<EntityData>,
+    dirty: Vec<Entity>,
+    is_dirty: Vec<bool>,
+    spawn_count: usize,
+    destroy_count: usize,
 }
 
 impl EntityManager {
@@ -11,19 +17,55 @@ impl EntityManager {
         Self {
             sparse: Vec::new(),
             destroyed: Vec::new(),
+            dirty: Vec::new(),
+            is_dirty: Vec::new(),
+            spawn_count: 0,
+            destroy_count: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            sparse: Vec::with_capacity(capacity),
+            destroyed: Vec::new(),
+            dirty: Vec::with_capacity(capacity),
+            is_dirty: Vec::with_capacity(capacity),
+            spawn_count: 0,
+            destroy_count: 0,
         }
     }
 
     #[must_use]
     pub fn spawn(&mut self) -> Entity {
-        if let Some(entity_data) = self.destroyed.pop() {
+        let entity = if let Some(entity_data) = self.destroyed.pop() {
             let entity = entity_data.owner();
             self.sparse[entity.id()] = Some(entity_data);
             entity
         } else {
             let entity = Entity::new(self.sparse.len());
             self.sparse.push(Some(EntityData::new(entity)));
+            self.is_dirty.push(false);
             entity
+        };
+        self.mark_dirty(entity);
+        self.spawn_count += 1;
+        entity
+    }
+
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.sparse.capacity()
+    }
+
+    #[must_use]
+    pub const fn stats(&self) -> EntityStats {
+        EntityStats {
+            spawned: self.spawn_count,
+            destroyed: self.destroy_count,
+            live: self.sparse.len() - self.destroyed.len(),
+            allocated: self.sparse.len(),
+            free: self.destroyed.len(),
         }
     }
 
@@ -32,6 +74,67 @@ impl EntityManager {
         self.sparse.get(owner.id())?.as_ref()
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &EntityData> {
+        self.sparse.iter().flatten()
+    }
+
+    #[must_use]
+    pub fn archetype(&self, owner: Entity) -> Option<&Archetype> {
+        self.get(owner).map(EntityData::archetype)
+    }
+
+    pub fn insert_component(&mut self, owner: Entity, id: usize) -> bool {
+        let Some(Some(entity_data)) = self.sparse.get_mut(owner.id()) else {
+            return false;
+        };
+        if !entity_data.archetype_mut().insert(id) {
+            self.mark_dirty(owner);
+        }
+        true
+    }
+
+    pub fn remove_component(&mut self, owner: Entity, id: usize) -> bool {
+        let Some(Some(entity_data)) = self.sparse.get_mut(owner.id()) else {
+            return false;
+        };
+        let is_removed = entity_data.archetype_mut().remove(id);
+        if is_removed {
+            self.mark_dirty(owner);
+        }
+        is_removed
+    }
+
+    pub fn set_enabled(&mut self, owner: Entity, value: bool) -> bool {
+        let Some(Some(entity_data)) = self.sparse.get_mut(owner.id()) else {
+            return false;
+        };
+        if entity_data.is_enabled() != value {
+            entity_data.set_enabled(value);
+            self.mark_dirty(owner);
+        }
+        true
+    }
+
+    #[must_use]
+    pub fn poll_dirty(&mut self) -> Option<Entity> {
+        let entity = self.dirty.pop()?;
+        self.is_dirty[entity.id()] = false;
+        Some(entity)
+    }
+
+    #[must_use]
+    pub fn depth(&self, entity: Entity) -> usize {
+        let mut depth = 0;
+        let mut current = entity;
+        while depth < self.sparse.len()
+            && let Some(parent) = self.get(current).and_then(EntityData::parent)
+        {
+            depth += 1;
+            current = parent;
+        }
+        depth
+    }
+
     pub fn bind(&mut self, parent: Entity, child: Entity) {
         if parent == child
             || !self.sparse.get(parent.id()).is_some_and(Option::is_some)
@@ -105,6 +208,15 @@ impl EntityManager {
         }
         entity_data.clear();
         self.destroyed.push(entity_data);
+        self.mark_dirty(entity);
+        self.destroy_count += 1;
+    }
+
+    fn mark_dirty(&mut self, entity: Entity) {
+        if !self.is_dirty[entity.id()] {
+            self.is_dirty[entity.id()] = true;
+            self.dirty.push(entity);
+        }
     }
 }
 
@@ -112,6 +224,124 @@ impl EntityManager {
 mod tests {
     use super::*;
 
+    #[must_use]
+    fn drain_dirty(entity_manager: &mut EntityManager) -> Vec<Entity> {
+        let mut dirty = Vec::new();
+        while let Some(entity) = entity_manager.poll_dirty() {
+            dirty.push(entity);
+        }
+        dirty
+    }
+
+    #[test]
+    fn dirty() {
+        let mut entity_manager = EntityManager::new();
+        let entity0 = entity_manager.spawn();
+        let entity1 = entity_manager.spawn();
+        assert!(entity_manager.insert_component(entity0, 0));
+        assert!(entity_manager.insert_component(entity0, 1));
+        assert_eq!(drain_dirty(&mut entity_manager), [entity1, entity0]);
+        assert!(entity_manager.insert_component(entity0, 0));
+        assert!(!entity_manager.remove_component(entity0, 2));
+        assert!(!entity_manager.remove_component(entity1, 0));
+        assert!(entity_manager.set_enabled(entity1, true));
+        assert!(drain_dirty(&mut entity_manager).is_empty());
+        assert!(entity_manager.remove_component(entity0, 0));
+        assert!(!entity_manager.remove_component(entity0, 0));
+        assert!(entity_manager.set_enabled(entity1, false));
+        assert!(entity_manager.insert_component(entity0, 0));
+        assert_eq!(drain_dirty(&mut entity_manager), [entity1, entity0]);
+        entity_manager.destroy(entity1);
+        assert!(!entity_manager.insert_component(entity1, 0));
+        assert!(!entity_manager.set_enabled(entity1, true));
+        assert_eq!(drain_dirty(&mut entity_manager), [entity1]);
+    }
+
+    #[test]
+    fn with_capacity() {
+        const CAPACITY: usize = 64;
+        let mut entity_manager = EntityManager::with_capacity(CAPACITY);
+        let mut default_manager = EntityManager::new();
+        let capacity = entity_manager.capacity();
+        assert!(capacity >= CAPACITY);
+        for _ in 0..CAPACITY {
+            assert_eq!(entity_manager.spawn(), default_manager.spawn());
+        }
+        assert_eq!(entity_manager.capacity(), capacity);
+        assert_eq!(entity_manager.stats(), default_manager.stats());
+    }
+
+    #[test]
+    fn stats() {
+        let mut entity_manager = EntityManager::new();
+        assert_eq!(entity_manager.stats(), EntityStats::default());
+        let entity0 = entity_manager.spawn();
+        let entity1 = entity_manager.spawn();
+        let entity2 = entity_manager.spawn();
+        entity_manager.bind(entity0, entity1);
+        entity_manager.destroy(entity0);
+        entity_manager.destroy(entity0);
+        assert_eq!(
+            entity_manager.stats(),
+            EntityStats {
+                spawned: 3,
+                destroyed: 2,
+                live: 1,
+                allocated: 3,
+                free: 2,
+            }
+        );
+        let _ = entity_manager.spawn();
+        entity_manager.destroy(entity2);
+        let _ = entity_manager.spawn();
+        let _ = entity_manager.spawn();
+        let _ = entity_manager.spawn();
+        assert_eq!(
+            entity_manager.stats(),
+            EntityStats {
+                spawned: 7,
+                destroyed: 3,
+                live: 4,
+                allocated: 4,
+                free: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn depth() {
+        let mut entity_manager = EntityManager::new();
+        let entity0 = entity_manager.spawn();
+        let entity1 = entity_manager.spawn();
+        let entity2 = entity_manager.spawn();
+        entity_manager.bind(entity0, entity1);
+        entity_manager.bind(entity1, entity2);
+        assert_eq!(entity_manager.depth(entity0), 0);
+        assert_eq!(entity_manager.depth(entity1), 1);
+        assert_eq!(entity_manager.depth(entity2), 2);
+        entity_manager.destroy(entity0);
+        assert_eq!(entity_manager.depth(entity2), 0);
+
+        let mut entity_manager = EntityManager::new();
+        let entity0 = entity_manager.spawn();
+        let entity1 = entity_manager.spawn();
+        assert!(
+            entity_manager.sparse[entity0.id()]
+                .as_mut()
+                .unwrap()
+                .set_parent(Some(entity1))
+                .is_ok()
+        );
+        assert!(
+            entity_manager.sparse[entity1.id()]
+                .as_mut()
+                .unwrap()
+                .set_parent(Some(entity0))
+                .is_ok()
+        );
+        assert_eq!(entity_manager.depth(entity0), 2);
+    }
+
     #[test]
     fn bind() {
         let mut entity_manager = EntityManager::new();
@@ -0,0 +1,8 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct EntityStats {
+    pub spawned: usize,
+    pub destroyed: usize,
+    pub live: usize,
+    pub allocated: usize,
+    pub free: usize,
+}
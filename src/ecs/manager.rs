@@ -1,11 +1,34 @@
+use core::any::Any;
+
 use super::{
-    Entity, archetype::Archetype, component_manager::ComponentManager,
-    entity_manager::EntityManager,
+    Commands, Entity, EntityStats, PoolView, PoolViewMut, archetype::Archetype,
+    component_manager::ComponentManager, entity_data::EntityData,
+    entity_manager::EntityManager, resource_manager::ResourceManager,
 };
+use crate::geometry::{Position, Vector};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AddError<T> {
+    Dead(T),
+    Replaced(T),
+}
+
+impl<T> AddError<T> {
+    #[must_use]
+    pub fn into_component(self) -> T {
+        match self {
+            Self::Dead(component) | Self::Replaced(component) => component,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct EntityDead;
 
 pub struct Manager {
     entities: EntityManager,
     components: ComponentManager,
+    resources: ResourceManager,
 }
 
 impl Manager {
@@ -14,17 +37,31 @@ impl Manager {
         Self {
             entities: EntityManager::new(),
             components: ComponentManager::new(),
+            resources: ResourceManager::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(entities: usize) -> Self {
+        Self {
+            entities: EntityManager::with_capacity(entities),
+            components: ComponentManager::with_capacity(entities),
+            resources: ResourceManager::new(),
         }
     }
 
     #[must_use]
     pub(super) fn component_id_or_register<T: 'static>(&mut self) -> usize {
-        self.components.id_or_register::<T>()
+        self.components.register::<T>()
     }
 
     #[must_use]
-    pub(super) fn entity_archetype(&self, owner: Entity) -> Option<&Archetype> {
-        self.entities.archetype(owner)
+    pub(super) fn entity_data(&self, owner: Entity) -> Option<&EntityData> {
+        self.entities.get(owner)
+    }
+
+    pub(super) fn all_entity_data(&self) -> impl Iterator<Item = &EntityData> {
+        self.entities.iter()
     }
 
     #[must_use]
@@ -37,11 +74,38 @@ impl Manager {
         self.entities.spawn()
     }
 
+    #[must_use]
+    pub const fn entity_stats(&self) -> EntityStats {
+        self.entities.stats()
+    }
+
     #[must_use]
     pub fn is_entity_alive(&self, entity: Entity) -> bool {
         self.entities.archetype(entity).is_some()
     }
 
+    #[must_use]
+    pub fn is_entity_enabled(&self, entity: Entity) -> Option<bool> {
+        Some(self.entities.get(entity)?.is_enabled())
+    }
+
+    pub fn set_enabled(
+        &mut self,
+        entity: Entity,
+        value: bool,
+    ) -> Result<(), ()> {
+        if self.entities.set_enabled(entity, value) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    #[must_use]
+    pub fn hierarchy_depth(&self, entity: Entity) -> usize {
+        self.entities.depth(entity)
+    }
+
     #[must_use]
     pub fn is_entity_has_common_with(
         &self,
@@ -92,17 +156,104 @@ impl Manager {
         entity_archetype.has(component_id)
     }
 
+    #[must_use]
+    pub fn has_component_by_id(&self, owner: Entity, id: usize) -> bool {
+        self.entities.archetype(owner).is_some_and(|a| a.has(id))
+    }
+
+    #[must_use]
+    pub fn component_ids(&self, owner: Entity) -> Vec<usize> {
+        let Some(owner_archetype) = self.entities.archetype(owner) else {
+            return Vec::new();
+        };
+        owner_archetype.iter().collect()
+    }
+
+    #[must_use]
+    pub fn shared_component_ids(
+        &self,
+        entity: Entity,
+        other: Entity,
+    ) -> Vec<usize> {
+        let (Some(entity_archetype), Some(other_archetype)) = (
+            self.entities.archetype(entity),
+            self.entities.archetype(other),
+        ) else {
+            return Vec::new();
+        };
+        entity_archetype.common_with(other_archetype)
+    }
+
+    #[must_use]
+    pub fn archetype_snapshot(&self, entity: Entity) -> Option<Archetype> {
+        self.entities.archetype(entity).cloned()
+    }
+
+    #[must_use]
+    pub fn components_added_since(
+        &self,
+        entity: Entity,
+        snapshot: &Archetype,
+    ) -> Vec<usize> {
+        self.entities
+            .archetype(entity)
+            .map_or_else(Vec::new, |a| a.added_since(snapshot))
+    }
+
+    #[must_use]
+    pub fn components_removed_since(
+        &self,
+        entity: Entity,
+        snapshot: &Archetype,
+    ) -> Vec<usize> {
+        self.entities
+            .archetype(entity)
+            .map_or_else(Vec::new, |a| a.removed_since(snapshot))
+    }
+
+    #[must_use]
+    pub fn component_type_names(&self, owner: Entity) -> Vec<&'static str> {
+        self.component_ids(owner)
+            .into_iter()
+            .filter_map(|id| self.components.type_name(id))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn registered_component_types(&self) -> Vec<(usize, &'static str)> {
+        self.components.registered_types()
+    }
+
     pub fn add_component<T: 'static>(
-        mut self,
+        &mut self,
         owner: Entity,
         component: T,
-    ) -> Result<(), T> {
-        let Some(owner_archetype) = self.entities.archetype_mut(owner) else {
-            return Err(component);
-        };
-        let component_id = self.components.id_or_register::<T>();
-        self.components.add(owner, component)?;
-        owner_archetype.add(component_id);
+    ) -> Result<(), AddError<T>> {
+        if !self.is_entity_alive(owner) {
+            return Err(AddError::Dead(component));
+        }
+        let component_id = self.components.register::<T>();
+        self.entities.insert_component(owner, component_id);
+        self.components
+            .insert(owner, component)
+            .map_or(Ok(()), |replaced| Err(AddError::Replaced(replaced)))
+    }
+
+    pub fn add_component_batch<T: Clone + 'static>(
+        &mut self,
+        entries: &[(Entity, T)],
+    ) -> Result<(), ()> {
+        if !entries
+            .iter()
+            .all(|(owner, _)| self.is_entity_alive(*owner))
+        {
+            return Err(());
+        }
+        let component_id = self.components.register::<T>();
+        for (owner, _) in entries {
+            self.entities.insert_component(*owner, component_id);
+        }
+        self.components.insert_batch(entries);
         Ok(())
     }
 
@@ -111,6 +262,26 @@ impl Manager {
         self.components.get(owner)
     }
 
+    pub fn try_component<T: 'static>(
+        &self,
+        owner: Entity,
+    ) -> Result<Option<&T>, EntityDead> {
+        if self.is_entity_alive(owner) {
+            Ok(self.components.get(owner))
+        } else {
+            Err(EntityDead)
+        }
+    }
+
+    #[must_use]
+    pub fn component_or<T: Clone + 'static>(
+        &self,
+        owner: Entity,
+        default: &T,
+    ) -> T {
+        self.component(owner).unwrap_or(default).clone()
+    }
+
     #[must_use]
     pub fn component_mut<T: 'static>(
         &mut self,
@@ -134,24 +305,297 @@ impl Manager {
         self.components.owners::<T>()
     }
 
+    #[must_use]
+    pub fn within_radius<P: Position + 'static>(
+        &self,
+        center: &Vector,
+        radius: f32,
+    ) -> Vec<Entity> {
+        let radius_squared = radius * radius;
+        self.entries::<P>()
+            .filter(|(_, p)| {
+                p.position().distance2_squared(center) <= radius_squared
+            })
+            .map(|(owner, _)| owner)
+            .collect()
+    }
+
+    /// Returns the owners of `T` in ascending id order, independent of the
+    /// insertion and removal history. This copies and sorts the owners, so it
+    /// costs O(n log n) per call, unlike `component_owners`.
+    #[must_use]
+    pub fn query_sorted<T: 'static>(&self) -> Vec<Entity> {
+        let mut owners = self.component_owners::<T>().to_vec();
+        owners.sort_unstable_by_key(|owner| owner.id());
+        owners
+    }
+
+    pub fn entries<T: 'static>(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.components.iter()
+    }
+
+    pub fn entries_mut<T: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, &mut T)> {
+        self.components.iter_mut()
+    }
+
+    pub fn query2<A: 'static, B: 'static>(
+        &self,
+    ) -> impl Iterator<Item = (Entity, &A, &B)> {
+        let owners_a = self.components.owners::<A>();
+        let owners_b = self.components.owners::<B>();
+        if owners_a.len() <= owners_b.len() {
+            owners_a
+        } else {
+            owners_b
+        }
+        .iter()
+        .filter_map(|owner| {
+            Some((
+                *owner,
+                self.components.get(*owner)?,
+                self.components.get(*owner)?,
+            ))
+        })
+    }
+
+    pub fn query2_mut<A: 'static, B: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, &mut A, &mut B)> {
+        self.components.zip_mut()
+    }
+
+    pub fn split_borrow<A: 'static, B: 'static>(
+        &mut self,
+    ) -> (PoolView<'_, A>, PoolViewMut<'_, B>) {
+        let (a, b) = self.components.split_mut();
+        (PoolView::new(a), PoolViewMut::new(b))
+    }
+
+    pub fn for_each<T: 'static, F: FnMut(Entity, &mut T, &mut Commands)>(
+        &mut self,
+        mut callback: F,
+    ) {
+        let mut commands = Commands::new();
+        for (owner, component) in self.components.iter_mut() {
+            callback(owner, component, &mut commands);
+        }
+        commands.apply(self);
+    }
+
+    #[must_use]
+    pub fn component_by_id(
+        &self,
+        owner: Entity,
+        id: usize,
+    ) -> Option<&dyn Any> {
+        self.components.get_by_id(id, owner)
+    }
+
+    #[must_use]
+    pub fn component_mut_by_id(
+        &mut self,
+        owner: Entity,
+        id: usize,
+    ) -> Option<&mut dyn Any> {
+        self.components.get_mut_by_id(id, owner)
+    }
+
+    pub fn take_component_by_id(
+        &mut self,
+        owner: Entity,
+        id: usize,
+    ) -> Option<Box<dyn Any>> {
+        if !self.entities.remove_component(owner, id) {
+            return None;
+        }
+        self.components.remove_by_id(id, owner)
+    }
+
+    pub fn take_component<T: 'static>(&mut self, owner: Entity) -> Option<T> {
+        let component_id = self.components.id::<T>()?;
+        if !self.entities.remove_component(owner, component_id) {
+            return None;
+        }
+        self.components.remove(owner)
+    }
+
+    pub fn move_component<T: 'static>(
+        &mut self,
+        from: Entity,
+        to: Entity,
+    ) -> Result<(), ()> {
+        if !self.is_entity_alive(to) {
+            return Err(());
+        }
+        let component = self.take_component::<T>(from).ok_or(())?;
+        match self.add_component(to, component) {
+            Ok(()) | Err(AddError::Replaced(_)) => Ok(()),
+            Err(AddError::Dead(_)) => Err(()),
+        }
+    }
+
+    pub fn swap_components<T: 'static>(
+        &mut self,
+        a: Entity,
+        b: Entity,
+    ) -> Result<(), ()> {
+        if self.components.swap::<T>(a, b) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
     pub fn remove_component<T: 'static>(&mut self, owner: Entity) {
-        let Some(owner_archetype) = self.entities.archetype_mut(owner) else {
+        self.take_component::<T>(owner);
+    }
+
+    pub fn remove_component_stable<T: 'static>(&mut self, owner: Entity) {
+        let Some(component_id) = self.components.id::<T>() else {
             return;
         };
+        if self.entities.remove_component(owner, component_id) {
+            self.components.remove_stable::<T>(owner);
+        }
+    }
+
+    pub fn sort_component_by<T: 'static, K: Ord, F: Fn(&T) -> K>(
+        &mut self,
+        key: F,
+    ) {
+        self.components.sort_by_key(key);
+    }
+
+    pub fn retain<T: 'static, F: FnMut(Entity, &mut T) -> bool>(
+        &mut self,
+        predicate: F,
+    ) {
         let Some(component_id) = self.components.id::<T>() else {
             return;
         };
-        if !owner_archetype.has(component_id) {
-            return;
+        for owner in self.components.retain(predicate) {
+            self.entities.remove_component(owner, component_id);
         }
-        self.components.remove::<T>(owner);
-        owner_archetype.remove(component_id);
+    }
+
+    pub fn take_pool<T: 'static>(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, T)> + use<T> {
+        let component_id = self.components.id::<T>();
+        let pool = self.components.take::<T>();
+        if let (Some(component_id), Some(pool)) = (component_id, &pool) {
+            for (owner, _) in pool.iter() {
+                self.entities.remove_component(owner, component_id);
+            }
+        }
+        pool.into_iter().flatten()
     }
 
     pub fn destroy_entity(&mut self, entity: Entity) {
-        self.components.remove_all(entity);
+        let Some(entity_data) = self.entities.get(entity) else {
+            return;
+        };
+        for child in entity_data.children().to_vec() {
+            self.destroy_entity(child);
+        }
+        self.components.destroy(entity);
         self.entities.destroy(entity);
     }
+
+    pub fn destroy_entities(&mut self, entities: &[Entity]) {
+        let mut branches = Vec::new();
+        for entity in entities {
+            self.collect_branch(*entity, &mut branches);
+        }
+        self.components.destroy_batch(&branches);
+        for entity in branches {
+            self.entities.destroy(entity);
+        }
+    }
+
+    pub fn destroy_reparenting(&mut self, entity: Entity) {
+        let Some(entity_data) = self.entities.get(entity) else {
+            return;
+        };
+        let parent = entity_data.parent();
+        for child in entity_data.children().to_vec() {
+            if let Some(parent) = parent {
+                self.entities.bind(parent, child);
+            } else {
+                self.entities.unbind(child);
+            }
+        }
+        self.components.destroy(entity);
+        self.entities.destroy(entity);
+    }
+
+    pub fn destroy_all<T: 'static>(&mut self) {
+        for owner in self.components.owners::<T>().to_vec() {
+            self.destroy_entity(owner);
+        }
+    }
+
+    pub fn insert_resource<T: 'static>(&mut self, resource: T) -> Option<T> {
+        self.resources.insert(resource)
+    }
+
+    #[must_use]
+    pub fn has_resource<T: 'static>(&self) -> bool {
+        self.resources.has::<T>()
+    }
+
+    #[must_use]
+    pub fn resource<T: 'static>(&self) -> Option<&T> {
+        self.resources.get()
+    }
+
+    #[must_use]
+    pub fn resource_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.resources.get_mut()
+    }
+
+    pub fn remove_resource<T: 'static>(&mut self) -> Option<T> {
+        self.resources.remove()
+    }
+
+    pub fn push_resource<T: 'static>(&mut self, resource: T) {
+        self.resources.push(resource);
+    }
+
+    pub fn pop_resource<T: 'static>(&mut self) -> Option<T> {
+        self.resources.pop()
+    }
+
+    pub fn resource_scope<R: 'static, T, F: FnOnce(&mut R, &mut Self) -> T>(
+        &mut self,
+        scope: F,
+    ) -> Option<T> {
+        let (mut resource, index) = self.resources.take::<R>()?;
+        let result = scope(&mut resource, self);
+        self.resources.restore(resource, index);
+        Some(result)
+    }
+
+    #[must_use]
+    pub fn resource_count(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn drain_resources(&mut self) -> impl Iterator<Item = Box<dyn Any>> {
+        self.resources.drain()
+    }
+
+    fn collect_branch(&self, entity: Entity, branch: &mut Vec<Entity>) {
+        let Some(entity_data) = self.entities.get(entity) else {
+            return;
+        };
+        for child in entity_data.children() {
+            self.collect_branch(*child, branch);
+        }
+        branch.push(entity);
+    }
 }
 
 impl Default for Manager {
@@ -159,3 +603,891 @@ impl Default for Manager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use super::*;
+    use crate::random::Rng;
+
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    struct Health(u8);
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Damage(u8);
+
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    struct Shield(u8);
+
+    #[test]
+    fn add_component() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert_eq!(manager.add_component(entity0, Health(1)), Ok(()));
+        assert_eq!(manager.component(entity0), Some(&Health(1)));
+        assert_eq!(
+            manager.add_component(entity0, Health(2)),
+            Err(AddError::Replaced(Health(1)))
+        );
+        assert_eq!(manager.component(entity0), Some(&Health(2)));
+        manager.destroy_entity(entity1);
+        let error = manager.add_component(entity1, Health(3)).unwrap_err();
+        assert_eq!(error, AddError::Dead(Health(3)));
+        assert_eq!(error.into_component(), Health(3));
+        assert!(manager.component::<Health>(entity1).is_none());
+    }
+
+    #[test]
+    fn add_component_batch() {
+        let mut batched = Manager::new();
+        let mut sequential = Manager::new();
+        let entities = (0..4)
+            .map(|_| {
+                let _ = sequential.spawn_entity();
+                batched.spawn_entity()
+            })
+            .collect::<Vec<_>>();
+        let entries = (0..)
+            .zip(&entities)
+            .map(|(health, entity)| (*entity, Health(health)))
+            .collect::<Vec<_>>();
+        assert!(batched.add_component_batch(&entries).is_ok());
+        for (owner, component) in entries.clone() {
+            assert!(sequential.add_component(owner, component).is_ok());
+        }
+        assert_eq!(
+            batched.all_component::<Health>(),
+            sequential.all_component::<Health>()
+        );
+        assert_eq!(
+            batched.component_owners::<Health>(),
+            sequential.component_owners::<Health>()
+        );
+        for entity in &entities {
+            assert_eq!(batched.component_ids(*entity), [0]);
+            assert!(batched.has_component::<Health>(*entity));
+        }
+        batched.destroy_entity(entities[3]);
+        assert!(batched.add_component_batch(&entries).is_err());
+        assert!(
+            batched
+                .add_component_batch(&[(entities[0], Shield(1))])
+                .is_ok()
+        );
+        assert_eq!(batched.component(entities[0]), Some(&Shield(1)));
+        assert!(!batched.has_component::<Shield>(entities[1]));
+    }
+
+    #[test]
+    fn with_capacity() {
+        const CAPACITY: usize = 64;
+        let mut manager = Manager::with_capacity(CAPACITY);
+        let mut default_manager = Manager::new();
+        let capacity = manager.entities.capacity();
+        assert!(capacity >= CAPACITY);
+        for health in (0..).take(CAPACITY) {
+            let entity = manager.spawn_entity();
+            assert_eq!(entity, default_manager.spawn_entity());
+            assert!(manager.add_component(entity, Health(health)).is_ok());
+            assert!(
+                default_manager
+                    .add_component(entity, Health(health))
+                    .is_ok()
+            );
+        }
+        assert_eq!(manager.entities.capacity(), capacity);
+        assert_eq!(manager.entity_stats(), default_manager.entity_stats());
+        assert_eq!(
+            manager.all_component::<Health>(),
+            default_manager.all_component::<Health>()
+        );
+    }
+
+    #[test]
+    fn entity_stats() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let _ = manager.spawn_entity();
+        manager.destroy_entity(entity0);
+        let _ = manager.spawn_entity();
+        let stats = manager.entity_stats();
+        assert_eq!(stats.spawned, 3);
+        assert_eq!(stats.destroyed, 1);
+        assert_eq!(stats.live, 2);
+        assert_eq!(stats.allocated, 2);
+        assert_eq!(stats.free, 0);
+    }
+
+    #[test]
+    fn component_ids() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Damage(2)).is_ok());
+        assert!(manager.add_component(entity0, Shield(3)).is_ok());
+        assert_eq!(manager.component_ids(entity0), [0, 2]);
+        assert_eq!(manager.component_ids(entity1), [1]);
+        manager.destroy_entity(entity1);
+        assert!(manager.component_ids(entity1).is_empty());
+    }
+
+    #[test]
+    fn shared_component_ids() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity0, Damage(2)).is_ok());
+        assert!(manager.add_component(entity0, Shield(3)).is_ok());
+        assert!(manager.add_component(entity1, Health(4)).is_ok());
+        assert!(manager.add_component(entity1, Shield(5)).is_ok());
+        assert!(manager.add_component(entity2, Damage(6)).is_ok());
+        assert_eq!(manager.shared_component_ids(entity0, entity1), [0, 2]);
+        assert_eq!(manager.shared_component_ids(entity1, entity0), [0, 2]);
+        assert_eq!(manager.shared_component_ids(entity0, entity2), [1]);
+        assert!(manager.shared_component_ids(entity1, entity2).is_empty());
+        manager.destroy_entity(entity1);
+        assert!(manager.shared_component_ids(entity0, entity1).is_empty());
+    }
+
+    #[test]
+    fn components_since() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity0, Damage(2)).is_ok());
+        let snapshot = manager.archetype_snapshot(entity0).unwrap();
+        assert!(
+            manager
+                .components_added_since(entity0, &snapshot)
+                .is_empty()
+        );
+        assert!(
+            manager
+                .components_removed_since(entity0, &snapshot)
+                .is_empty()
+        );
+        manager.remove_component::<Health>(entity0);
+        assert!(manager.add_component(entity0, Shield(3)).is_ok());
+        assert_eq!(manager.components_added_since(entity0, &snapshot), [2]);
+        assert_eq!(manager.components_removed_since(entity0, &snapshot), [0]);
+        manager.destroy_entity(entity1);
+        assert!(manager.archetype_snapshot(entity1).is_none());
+        assert!(
+            manager
+                .components_added_since(entity1, &snapshot)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn component_type_names() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Damage(2)).is_ok());
+        assert!(manager.add_component(entity0, Shield(3)).is_ok());
+        assert_eq!(
+            manager.component_type_names(entity0),
+            [any::type_name::<Health>(), any::type_name::<Shield>()]
+        );
+        assert_eq!(
+            manager.component_type_names(entity1),
+            [any::type_name::<Damage>()]
+        );
+    }
+
+    #[test]
+    fn registered_component_types() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        assert!(manager.registered_component_types().is_empty());
+        assert!(manager.add_component(entity0, Shield(1)).is_ok());
+        let health_id = manager.component_id_or_register::<Health>();
+        assert_eq!(
+            manager.registered_component_types(),
+            [
+                (0, any::type_name::<Shield>()),
+                (health_id, any::type_name::<Health>()),
+            ]
+        );
+    }
+
+    #[test]
+    fn try_component() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert_eq!(manager.try_component(entity0), Ok(Some(&Health(1))));
+        assert_eq!(manager.try_component::<Health>(entity1), Ok(None));
+        assert_eq!(manager.try_component::<Damage>(entity0), Ok(None));
+        manager.destroy_entity(entity0);
+        assert_eq!(manager.try_component::<Health>(entity0), Err(EntityDead));
+        let entity2 = manager.spawn_entity();
+        assert_eq!(manager.try_component::<Health>(entity2), Ok(None));
+    }
+
+    #[test]
+    fn component_or() {
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        struct Tint(u8);
+
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Tint(1)).is_ok());
+        assert_eq!(manager.component_or(entity0, &Tint(255)), Tint(1));
+        assert_eq!(manager.component_or(entity1, &Tint(255)), Tint(255));
+        assert!(!manager.has_component::<Tint>(entity1));
+        assert_eq!(manager.component_owners::<Tint>(), [entity0]);
+    }
+
+    #[test]
+    fn component_by_id() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity0, Damage(2)).is_ok());
+        assert!(manager.add_component(entity1, Damage(3)).is_ok());
+        let [health, damage] = manager.component_ids(entity0)[..] else {
+            panic!();
+        };
+        assert!(manager.has_component_by_id(entity0, health));
+        assert!(!manager.has_component_by_id(entity1, health));
+        assert!(manager.has_component_by_id(entity1, damage));
+        assert_eq!(
+            manager
+                .component_by_id(entity1, damage)
+                .unwrap()
+                .downcast_ref(),
+            Some(&Damage(3))
+        );
+        manager
+            .component_mut_by_id(entity0, health)
+            .unwrap()
+            .downcast_mut::<Health>()
+            .unwrap()
+            .0 += 1;
+        assert_eq!(manager.component(entity0), Some(&Health(2)));
+        assert_eq!(
+            *manager
+                .take_component_by_id(entity0, health)
+                .unwrap()
+                .downcast::<Health>()
+                .unwrap(),
+            Health(2)
+        );
+        assert!(!manager.has_component_by_id(entity0, health));
+        assert!(!manager.has_component::<Health>(entity0));
+        assert!(manager.take_component_by_id(entity0, health).is_none());
+        assert!(manager.take_component_by_id(entity1, 3).is_none());
+        manager.destroy_entity(entity1);
+        assert!(!manager.has_component_by_id(entity1, damage));
+        assert!(manager.take_component_by_id(entity1, damage).is_none());
+    }
+
+    #[test]
+    fn take_component() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity0, Damage(2)).is_ok());
+        assert!(manager.take_component::<Health>(entity1).is_none());
+        assert!(manager.take_component::<Shield>(entity0).is_none());
+        assert_eq!(manager.take_component(entity0), Some(Health(1)));
+        assert!(!manager.has_component::<Health>(entity0));
+        assert!(manager.component::<Health>(entity0).is_none());
+        assert!(manager.take_component::<Health>(entity0).is_none());
+        assert!(manager.has_component::<Damage>(entity0));
+        manager.destroy_entity(entity0);
+        assert!(manager.take_component::<Damage>(entity0).is_none());
+    }
+
+    #[test]
+    fn move_component() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.move_component::<Health>(entity0, entity1).is_ok());
+        assert!(!manager.has_component::<Health>(entity0));
+        assert_eq!(manager.component(entity1), Some(&Health(1)));
+        assert!(manager.move_component::<Health>(entity0, entity1).is_err());
+        assert_eq!(manager.component(entity1), Some(&Health(1)));
+        manager.destroy_entity(entity2);
+        assert!(manager.move_component::<Health>(entity1, entity2).is_err());
+        assert_eq!(manager.component(entity1), Some(&Health(1)));
+        assert!(manager.move_component::<Health>(entity2, entity0).is_err());
+    }
+
+    #[test]
+    fn remove_component_stable() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.add_component(entity2, Health(3)).is_ok());
+        manager.remove_component_stable::<Health>(entity0);
+        assert!(!manager.has_component::<Health>(entity0));
+        assert_eq!(manager.all_component::<Health>(), [Health(2), Health(3)]);
+        assert_eq!(manager.component_owners::<Health>(), [entity1, entity2]);
+    }
+
+    #[test]
+    fn sort_component_by() {
+        let mut manager = Manager::new();
+        let entities = [(); 5].map(|()| manager.spawn_entity());
+        for (entity, health) in entities.into_iter().zip([3, 1, 4, 1, 5]) {
+            assert!(manager.add_component(entity, Health(health)).is_ok());
+        }
+        manager.sort_component_by::<Health, _, _>(|health| health.0);
+        assert!(
+            manager
+                .entries::<Health>()
+                .map(|(_, health)| health.0)
+                .eq([1, 1, 3, 4, 5])
+        );
+        assert_eq!(
+            manager.component_owners::<Health>(),
+            [
+                entities[1],
+                entities[3],
+                entities[0],
+                entities[2],
+                entities[4]
+            ]
+        );
+        for (entity, health) in entities.into_iter().zip([3, 1, 4, 1, 5]) {
+            assert!(manager.has_component::<Health>(entity));
+            assert_eq!(manager.component(entity), Some(&Health(health)));
+        }
+        manager.sort_component_by::<Damage, _, _>(|damage| damage.0);
+    }
+
+    #[test]
+    fn split_borrow() {
+        let mut manager = Manager::new();
+        let entities =
+            (0..100).map(|_| manager.spawn_entity()).collect::<Vec<_>>();
+        for (health, entity) in (0..).zip(&entities) {
+            assert!(manager.add_component(*entity, Health(health)).is_ok());
+            if health % 2 == 0 {
+                assert!(manager.add_component(*entity, Damage(1)).is_ok());
+            }
+        }
+        let (health, mut damage) = manager.split_borrow::<Health, Damage>();
+        assert_eq!(health.owners().len(), 100);
+        for owner in damage.owners().to_vec() {
+            assert!(health.has(owner));
+            damage.get_mut(owner).unwrap().0 += health.get(owner).unwrap().0;
+        }
+        assert!(damage.iter().all(|(owner, damage)| {
+            damage.0 == health.get(owner).unwrap().0 + 1
+        }));
+        assert!(!damage.has(entities[1]));
+        assert_eq!(manager.component(entities[4]), Some(&Damage(5)));
+        assert_eq!(manager.component(entities[4]), Some(&Health(4)));
+        let (shield, _) = manager.split_borrow::<Shield, Health>();
+        assert_eq!(shield.iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "left != right")]
+    fn split_borrow_same() {
+        let _ = Manager::new().split_borrow::<Health, Health>();
+    }
+
+    #[test]
+    fn retain() {
+        let mut manager = Manager::new();
+        let entities =
+            (0..6).map(|_| manager.spawn_entity()).collect::<Vec<_>>();
+        for (health, entity) in (0..).zip(&entities) {
+            assert!(manager.add_component(*entity, Health(health)).is_ok());
+        }
+        assert!(manager.add_component(entities[0], Damage(1)).is_ok());
+        manager.retain::<Health, _>(|_, health| health.0 % 2 == 1);
+        assert_eq!(
+            manager.all_component::<Health>(),
+            [Health(1), Health(3), Health(5)]
+        );
+        assert_eq!(
+            manager.component_owners::<Health>(),
+            [entities[1], entities[3], entities[5]]
+        );
+        for (i, entity) in entities.iter().enumerate() {
+            assert_eq!(manager.has_component::<Health>(*entity), i % 2 == 1);
+        }
+        assert!(manager.has_component::<Damage>(entities[0]));
+        manager.retain::<Shield, _>(|_, _| false);
+    }
+
+    #[test]
+    fn take_pool() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(0)).is_ok());
+        assert!(manager.add_component(entity1, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Damage(1)).is_ok());
+        while manager.poll_dirty().is_some() {}
+        let health = manager.take_pool::<Health>();
+        assert!(!manager.has_component::<Health>(entity0));
+        assert!(!manager.has_component::<Health>(entity1));
+        assert!(manager.has_component::<Damage>(entity1));
+        assert!(manager.all_component::<Health>().is_empty());
+        assert_eq!(
+            health.collect::<Vec<_>>(),
+            [(entity0, Health(0)), (entity1, Health(1))]
+        );
+        assert_eq!(manager.poll_dirty(), Some(entity1));
+        assert_eq!(manager.poll_dirty(), Some(entity0));
+        assert!(manager.poll_dirty().is_none());
+        assert_eq!(manager.take_pool::<Health>().count(), 0);
+        assert_eq!(manager.take_pool::<Shield>().count(), 0);
+        assert!(manager.add_component(entity0, Health(2)).is_ok());
+        assert_eq!(manager.component(entity0), Some(&Health(2)));
+    }
+
+    #[test]
+    fn entries() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(0)).is_ok());
+        assert!(manager.add_component(entity1, Health(1)).is_ok());
+        assert!(manager.add_component(entity2, Health(2)).is_ok());
+        manager.remove_component::<Health>(entity0);
+        for (owner, health) in manager.entries_mut::<Health>() {
+            health.0 += 3;
+            assert_ne!(owner, entity0);
+        }
+        let mut owners = Vec::new();
+        for (owner, health) in manager.entries::<Health>() {
+            assert_eq!(manager.component(owner), Some(health));
+            owners.push(owner);
+        }
+        assert_eq!(owners.len(), 2);
+        assert!(owners.contains(&entity1) && owners.contains(&entity2));
+        assert_eq!(manager.component(entity1), Some(&Health(4)));
+        assert_eq!(manager.component(entity2), Some(&Health(5)));
+        assert_eq!(manager.entries::<Shield>().count(), 0);
+    }
+
+    #[test]
+    fn query2() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        let entity3 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.add_component(entity1, Damage(3)).is_ok());
+        assert!(manager.add_component(entity2, Damage(4)).is_ok());
+        assert!(manager.add_component(entity3, Damage(5)).is_ok());
+        assert!(manager.add_component(entity3, Health(6)).is_ok());
+        assert_eq!(
+            manager.query2::<Health, Damage>().collect::<Vec<_>>(),
+            [
+                (entity1, &Health(2), &Damage(3)),
+                (entity3, &Health(6), &Damage(5))
+            ]
+        );
+        assert_eq!(
+            manager.query2::<Damage, Health>().collect::<Vec<_>>(),
+            [
+                (entity1, &Damage(3), &Health(2)),
+                (entity3, &Damage(5), &Health(6))
+            ]
+        );
+        assert_eq!(manager.query2::<Health, Shield>().count(), 0);
+    }
+
+    #[test]
+    fn query2_mut() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.add_component(entity1, Damage(3)).is_ok());
+        assert!(manager.add_component(entity2, Damage(4)).is_ok());
+        for (owner, health, damage) in manager.query2_mut::<Health, Damage>() {
+            assert_eq!(owner, entity1);
+            health.0 -= damage.0 - 1;
+            damage.0 += 1;
+        }
+        assert_eq!(manager.component(entity0), Some(&Health(1)));
+        assert_eq!(manager.component(entity1), Some(&Health(0)));
+        assert_eq!(manager.component(entity1), Some(&Damage(4)));
+        assert_eq!(manager.component(entity2), Some(&Damage(4)));
+    }
+
+    #[test]
+    fn query2_mut_order() {
+        let mut manager = Manager::new();
+        let entities = [(); 5].map(|()| manager.spawn_entity());
+        for (entity, health) in entities.into_iter().zip([3, 1, 4, 1, 5]) {
+            assert!(manager.add_component(entity, Health(health)).is_ok());
+        }
+        assert!(manager.add_component(entities[4], Damage(1)).is_ok());
+        assert!(manager.add_component(entities[2], Damage(1)).is_ok());
+        manager.sort_component_by::<Health, _, _>(|health| health.0);
+        let owners = manager.component_owners::<Health>().to_vec();
+        for (_, health, damage) in manager.query2_mut::<Health, Damage>() {
+            health.0 += damage.0;
+        }
+        assert_eq!(manager.component_owners::<Health>(), owners);
+        assert!(
+            manager
+                .entries::<Health>()
+                .map(|(_, health)| health.0)
+                .eq([1, 1, 3, 5, 6])
+        );
+        assert_eq!(
+            manager.component_owners::<Damage>(),
+            [entities[4], entities[2]]
+        );
+    }
+
+    #[test]
+    fn for_each() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(0)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.add_component(entity2, Health(3)).is_ok());
+        let mut owners = Vec::new();
+        manager.for_each::<Health, _>(|owner, health, commands| {
+            owners.push(owner);
+            if health.0 == 0 {
+                commands.destroy_entity(owner);
+            } else {
+                health.0 -= 1;
+                commands.add_component(owner, Damage(health.0));
+            }
+            commands.remove_component::<Health>(entity2);
+        });
+        assert_eq!(owners, [entity0, entity1, entity2]);
+        assert!(!manager.is_entity_alive(entity0));
+        assert_eq!(manager.component(entity1), Some(&Health(1)));
+        assert_eq!(manager.component(entity1), Some(&Damage(1)));
+        assert!(manager.component::<Health>(entity2).is_none());
+        assert_eq!(manager.component(entity2), Some(&Damage(2)));
+        manager.for_each::<Shield, _>(|_, _, _| unreachable!());
+    }
+
+    #[test]
+    fn destroy_reparenting() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        let entity3 = manager.spawn_entity();
+        let entity4 = manager.spawn_entity();
+        manager.entities.bind(entity0, entity1);
+        manager.entities.bind(entity1, entity2);
+        manager.entities.bind(entity1, entity3);
+        manager.entities.bind(entity3, entity4);
+        assert!(manager.add_component(entity1, Health(1)).is_ok());
+        assert!(manager.add_component(entity2, Health(2)).is_ok());
+        manager.destroy_reparenting(entity1);
+        assert!(!manager.is_entity_alive(entity1));
+        assert!(manager.component::<Health>(entity1).is_none());
+        assert_eq!(manager.component(entity2), Some(&Health(2)));
+        for entity in [entity2, entity3] {
+            assert_eq!(
+                manager.entities.get(entity).unwrap().parent(),
+                Some(entity0)
+            );
+            assert_eq!(manager.hierarchy_depth(entity), 1);
+        }
+        assert_eq!(manager.entities.get(entity0).unwrap().children().len(), 2);
+        assert_eq!(
+            manager.entities.get(entity4).unwrap().parent(),
+            Some(entity3)
+        );
+        assert_eq!(manager.hierarchy_depth(entity4), 2);
+        manager.destroy_reparenting(entity0);
+        assert!(!manager.is_entity_alive(entity0));
+        for entity in [entity2, entity3] {
+            assert!(manager.entities.get(entity).unwrap().parent().is_none());
+        }
+        assert_eq!(manager.hierarchy_depth(entity4), 1);
+        manager.destroy_reparenting(entity0);
+    }
+
+    #[test]
+    fn destroy_all() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        let entity3 = manager.spawn_entity();
+        manager.entities.bind(entity0, entity1);
+        manager.entities.bind(entity1, entity2);
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.add_component(entity1, Shield(3)).is_ok());
+        assert!(manager.add_component(entity2, Damage(4)).is_ok());
+        assert!(manager.add_component(entity3, Damage(5)).is_ok());
+        manager.destroy_all::<Shield>();
+        assert!(manager.is_entity_alive(entity0));
+        assert!(!manager.is_entity_alive(entity1));
+        assert!(!manager.is_entity_alive(entity2));
+        assert!(manager.is_entity_alive(entity3));
+        assert!(manager.all_component::<Shield>().is_empty());
+        assert_eq!(manager.all_component::<Health>(), [Health(1)]);
+        assert_eq!(manager.all_component::<Damage>(), [Damage(5)]);
+        manager.destroy_all::<Health>();
+        assert!(!manager.is_entity_alive(entity0));
+        assert!(manager.is_entity_alive(entity3));
+    }
+
+    #[test]
+    fn destroy_entities() {
+        #[must_use]
+        fn setup() -> (Manager, [Entity; 5]) {
+            let mut manager = Manager::new();
+            let entities = [(); 5].map(|()| manager.spawn_entity());
+            manager.entities.bind(entities[0], entities[1]);
+            manager.entities.bind(entities[1], entities[2]);
+            for (health, entity) in (0..).zip(entities) {
+                assert!(manager.add_component(entity, Health(health)).is_ok());
+            }
+            assert!(manager.add_component(entities[1], Shield(5)).is_ok());
+            assert!(manager.add_component(entities[4], Shield(6)).is_ok());
+            (manager, entities)
+        }
+
+        let (mut sequential, entities) = setup();
+        for entity in [entities[0], entities[3], entities[2]] {
+            sequential.destroy_entity(entity);
+        }
+        let (mut batched, _) = setup();
+        batched.destroy_entities(&[entities[0], entities[3], entities[2]]);
+        for entity in entities {
+            assert_eq!(
+                batched.is_entity_alive(entity),
+                sequential.is_entity_alive(entity)
+            );
+        }
+        assert!(batched.is_entity_alive(entities[4]));
+        assert_eq!(batched.entity_stats(), sequential.entity_stats());
+        assert_eq!(
+            batched.all_component::<Health>(),
+            sequential.all_component::<Health>()
+        );
+        assert_eq!(
+            batched.all_component::<Shield>(),
+            sequential.all_component::<Shield>()
+        );
+        assert_eq!(
+            batched.component_owners::<Health>(),
+            sequential.component_owners::<Health>()
+        );
+        assert_eq!(batched.spawn_entity(), sequential.spawn_entity());
+        batched.destroy_entities(&[]);
+    }
+
+    #[test]
+    fn within_radius() {
+        use crate::geometry::Transform;
+
+        const CENTER: Vector = Vector::new(1.0, 1.0, 0.0);
+        let mut manager = Manager::new();
+        let inside = manager.spawn_entity();
+        let boundary = manager.spawn_entity();
+        let outside = manager.spawn_entity();
+        let _ = manager.spawn_entity();
+        for (entity, position) in [
+            (inside, Vector::new(2.0, 2.0, 50.0)),
+            (boundary, Vector::new(4.0, 5.0, 0.0)),
+            (outside, Vector::new(-3.0, 4.1, 0.0)),
+        ] {
+            assert!(
+                manager
+                    .add_component(entity, Transform::new(position, 0.0, 1.0))
+                    .is_ok()
+            );
+        }
+        let mut found = manager.within_radius::<Transform>(&CENTER, 5.0);
+        found.sort_unstable_by_key(|entity| entity.id());
+        assert_eq!(found, [inside, boundary]);
+        assert_eq!(manager.within_radius::<Transform>(&CENTER, 2.0), [inside]);
+        assert!(
+            manager
+                .within_radius::<Transform>(&Vector::new(100.0, 0.0, 0.0), 5.0)
+                .is_empty()
+        );
+        assert!(
+            Manager::new()
+                .within_radius::<Transform>(&CENTER, 5.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn query_sorted() {
+        let mut manager = Manager::new();
+        let entities = [(); 6].map(|()| manager.spawn_entity());
+        for (health, entity) in (0..).zip(entities.iter().rev()) {
+            assert!(manager.add_component(*entity, Health(health)).is_ok());
+        }
+        manager.remove_component::<Health>(entities[4]);
+        manager.remove_component::<Health>(entities[1]);
+        assert!(manager.add_component(entities[1], Health(6)).is_ok());
+        manager.destroy_entity(entities[2]);
+        let sorted = manager.query_sorted::<Health>();
+        assert_eq!(
+            sorted,
+            [entities[0], entities[1], entities[3], entities[5]]
+        );
+        assert!(sorted.windows(2).all(|w| w[0].id() < w[1].id()));
+        assert!(manager.query_sorted::<Damage>().is_empty());
+    }
+
+    #[test]
+    fn swap_components() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        let entity2 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(manager.swap_components::<Health>(entity0, entity1).is_ok());
+        assert_eq!(manager.component(entity0), Some(&Health(2)));
+        assert_eq!(manager.component(entity1), Some(&Health(1)));
+        assert!(manager.has_component::<Health>(entity0));
+        assert!(manager.has_component::<Health>(entity1));
+        assert!(manager.swap_components::<Health>(entity0, entity2).is_err());
+        assert!(manager.swap_components::<Damage>(entity0, entity1).is_err());
+        assert_eq!(manager.component(entity0), Some(&Health(2)));
+    }
+
+    #[test]
+    fn poll_dirty() {
+        let mut manager = Manager::new();
+        let entity = manager.spawn_entity();
+        for _ in 0..3 {
+            let _ = manager.add_component(entity, Health(1));
+        }
+        assert_eq!(manager.poll_dirty(), Some(entity));
+        assert!(manager.poll_dirty().is_none());
+        assert!(manager.add_component(entity, Health(2)).is_err());
+        assert!(manager.take_component::<Damage>(entity).is_none());
+        manager.remove_component_stable::<Shield>(entity);
+        assert!(manager.poll_dirty().is_none());
+        assert!(manager.add_component(entity, Damage(1)).is_ok());
+        manager.remove_component::<Health>(entity);
+        assert_eq!(manager.poll_dirty(), Some(entity));
+        assert!(manager.poll_dirty().is_none());
+    }
+
+    #[test]
+    fn push_pop_resource() {
+        let mut manager = Manager::new();
+        manager.push_resource(Health(1));
+        manager.push_resource(Health(2));
+        assert_eq!(
+            manager.resource_scope(|health: &mut Health, _| health.0),
+            Some(2)
+        );
+        assert_eq!(manager.pop_resource(), Some(Health(2)));
+        assert_eq!(manager.resource(), Some(&Health(1)));
+        assert_eq!(manager.pop_resource(), Some(Health(1)));
+        assert!(manager.pop_resource::<Health>().is_none());
+    }
+
+    #[test]
+    fn resource_scope() {
+        struct DeltaTime(u8);
+
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(manager.add_component(entity0, Health(1)).is_ok());
+        assert!(manager.add_component(entity1, Health(2)).is_ok());
+        assert!(
+            manager
+                .resource_scope::<DeltaTime, _, _>(|_, _| ())
+                .is_none()
+        );
+        assert!(manager.insert_resource(DeltaTime(3)).is_none());
+        let count = manager.resource_scope(|dt: &mut DeltaTime, manager| {
+            assert!(!manager.has_resource::<DeltaTime>());
+            for (_, health) in manager.entries_mut::<Health>() {
+                health.0 += dt.0;
+            }
+            dt.0 += 1;
+            manager.all_component::<Health>().len()
+        });
+        assert_eq!(count, Some(2));
+        assert_eq!(manager.component(entity0), Some(&Health(4)));
+        assert_eq!(manager.component(entity1), Some(&Health(5)));
+        assert_eq!(manager.resource::<DeltaTime>().map(|dt| dt.0), Some(4));
+    }
+
+    #[test]
+    fn resource_scope_order() {
+        let mut manager = Manager::new();
+        assert!(manager.insert_resource(Health(1)).is_none());
+        assert!(manager.insert_resource(Damage(2)).is_none());
+        assert!(manager.insert_resource(Shield(3)).is_none());
+        let scoped = manager.resource_scope(|damage: &mut Damage, manager| {
+            assert!(manager.insert_resource(Damage(0)).is_none());
+            damage.0 += 1;
+        });
+        assert!(scoped.is_some());
+        let mut drained = manager.drain_resources();
+        assert_eq!(
+            *drained.next().unwrap().downcast::<Health>().unwrap(),
+            Health(1)
+        );
+        assert_eq!(
+            *drained.next().unwrap().downcast::<Damage>().unwrap(),
+            Damage(3)
+        );
+        assert_eq!(
+            *drained.next().unwrap().downcast::<Shield>().unwrap(),
+            Shield(3)
+        );
+        assert!(drained.next().is_none());
+    }
+
+    #[test]
+    fn resource() {
+        let mut manager = Manager::new();
+        assert!(manager.insert_resource(Rng::seeded(7)).is_none());
+        assert!(manager.has_resource::<Rng>());
+        let value = manager.resource_mut::<Rng>().unwrap().next_u32();
+        assert_eq!(value, Rng::seeded(7).next_u32());
+        assert_ne!(manager.resource::<Rng>(), Some(&Rng::seeded(7)));
+        assert!(manager.remove_resource::<Rng>().is_some());
+        assert!(!manager.has_resource::<Rng>());
+        assert!(manager.resource::<Rng>().is_none());
+        assert!(manager.insert_resource(Rng::seeded(7)).is_none());
+        assert!(manager.insert_resource(Health(1)).is_none());
+        assert_eq!(manager.resource_count(), 2);
+        assert_eq!(manager.drain_resources().count(), 2);
+        assert_eq!(manager.resource_count(), 0);
+        assert!(!manager.has_resource::<Health>());
+    }
+}
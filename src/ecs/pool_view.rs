@@ -0,0 +1,73 @@
+use super::{
+    Entity,
+    component_pool::{AnyComponentPool, ComponentPool},
+};
+
+pub struct PoolView<'a, T> {
+    pool: &'a ComponentPool<T>,
+}
+
+impl<'a, T: 'static> PoolView<'a, T> {
+    #[must_use]
+    pub(super) const fn new(pool: &'a ComponentPool<T>) -> Self {
+        Self { pool }
+    }
+
+    #[must_use]
+    pub fn has(&self, owner: Entity) -> bool {
+        self.pool.has(owner)
+    }
+
+    #[must_use]
+    pub fn get(&self, owner: Entity) -> Option<&'a T> {
+        self.pool.get(owner)
+    }
+
+    #[must_use]
+    pub fn owners(&self) -> &'a [Entity] {
+        self.pool.owners()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &'a T)> {
+        self.pool.iter()
+    }
+}
+
+pub struct PoolViewMut<'a, T> {
+    pool: &'a mut ComponentPool<T>,
+}
+
+impl<'a, T: 'static> PoolViewMut<'a, T> {
+    #[must_use]
+    pub(super) const fn new(pool: &'a mut ComponentPool<T>) -> Self {
+        Self { pool }
+    }
+
+    #[must_use]
+    pub fn has(&self, owner: Entity) -> bool {
+        self.pool.has(owner)
+    }
+
+    #[must_use]
+    pub fn get(&self, owner: Entity) -> Option<&T> {
+        self.pool.get(owner)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, owner: Entity) -> Option<&mut T> {
+        self.pool.get_mut(owner)
+    }
+
+    #[must_use]
+    pub fn owners(&self) -> &[Entity] {
+        self.pool.owners()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> {
+        self.pool.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Entity, &mut T)> {
+        self.pool.iter_mut()
+    }
+}
@@ -2,35 +2,71 @@ use core::{
     any::{Any, TypeId},
     hash::BuildHasherDefault,
 };
-use std::collections::HashMap;
+use std::{collections::HashMap, mem};
 
 use super::noop_hasher::NoopHasher;
 
-pub struct ResourceManager(
-    HashMap<TypeId, Box<dyn Any>, BuildHasherDefault<NoopHasher>>,
-);
+pub struct ResourceManager {
+    resources: HashMap<TypeId, Box<dyn Any>, BuildHasherDefault<NoopHasher>>,
+    layers: HashMap<TypeId, Vec<Box<dyn Any>>, BuildHasherDefault<NoopHasher>>,
+    order: Vec<TypeId>,
+}
 
 impl ResourceManager {
     #[must_use]
     pub fn new() -> Self {
-        Self(HashMap::default())
+        Self {
+            resources: HashMap::default(),
+            layers: HashMap::default(),
+            order: Vec::new(),
+        }
     }
 
     pub fn insert<T: 'static>(&mut self, resource: T) -> Option<T> {
-        self.0
+        let previous = self
+            .resources
             .insert(TypeId::of::<T>(), Box::new(resource))
-            .map(|r| *r.downcast().unwrap())
+            .map(|r| *r.downcast().unwrap());
+        if previous.is_none() {
+            self.order.push(TypeId::of::<T>());
+        }
+        previous
+    }
+
+    pub fn push<T: 'static>(&mut self, resource: T) {
+        if let Some(previous) =
+            self.resources.insert(TypeId::of::<T>(), Box::new(resource))
+        {
+            self.layers
+                .entry(TypeId::of::<T>())
+                .or_default()
+                .push(previous);
+        } else {
+            self.order.push(TypeId::of::<T>());
+        }
+    }
+
+    pub fn pop<T: 'static>(&mut self) -> Option<T> {
+        let resource = self.resources.remove(&TypeId::of::<T>())?;
+        if let Some(previous) =
+            self.layers.get_mut(&TypeId::of::<T>()).and_then(Vec::pop)
+        {
+            self.resources.insert(TypeId::of::<T>(), previous);
+        } else {
+            self.order.retain(|id| *id != TypeId::of::<T>());
+        }
+        Some(*resource.downcast().unwrap())
     }
 
     #[must_use]
     pub fn has<T: 'static>(&self) -> bool {
-        self.0.contains_key(&TypeId::of::<T>())
+        self.resources.contains_key(&TypeId::of::<T>())
     }
 
     #[must_use]
     pub fn get<T: 'static>(&self) -> Option<&T> {
         Some(
-            (**self.0.get(&TypeId::of::<T>())?)
+            (**self.resources.get(&TypeId::of::<T>())?)
                 .downcast_ref::<T>()
                 .unwrap(),
         )
@@ -39,25 +75,71 @@ impl ResourceManager {
     #[must_use]
     pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
         Some(
-            (**self.0.get_mut(&TypeId::of::<T>())?)
+            (**self.resources.get_mut(&TypeId::of::<T>())?)
                 .downcast_mut()
                 .unwrap(),
         )
     }
 
     pub fn remove<T: 'static>(&mut self) -> Option<T> {
-        self.0
-            .remove(&TypeId::of::<T>())
-            .map(|r| *r.downcast().unwrap())
+        let resource = self.resources.remove(&TypeId::of::<T>())?;
+        self.layers.remove(&TypeId::of::<T>());
+        self.order.retain(|id| *id != TypeId::of::<T>());
+        Some(*resource.downcast().unwrap())
+    }
+
+    pub fn take<T: 'static>(&mut self) -> Option<(T, usize)> {
+        let resource = self.resources.remove(&TypeId::of::<T>())?;
+        let index = self
+            .order
+            .iter()
+            .position(|id| *id == TypeId::of::<T>())
+            .unwrap();
+        self.order.remove(index);
+        Some((*resource.downcast().unwrap(), index))
+    }
+
+    pub fn restore<T: 'static>(&mut self, resource: T, index: usize) {
+        self.order.retain(|id| *id != TypeId::of::<T>());
+        self.order
+            .insert(index.min(self.order.len()), TypeId::of::<T>());
+        self.resources.insert(TypeId::of::<T>(), Box::new(resource));
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    #[must_use]
+    pub fn type_ids(&self) -> &[TypeId] {
+        &self.order
+    }
+
+    pub fn drain(&mut self) -> impl Iterator<Item = Box<dyn Any>> {
+        self.layers.clear();
+        let mut resources = mem::take(&mut self.resources);
+        mem::take(&mut self.order)
+            .into_iter()
+            .map(move |id| resources.remove(&id).unwrap())
     }
 
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.resources.clear();
+        self.layers.clear();
+        self.order.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::{cell::Cell, rc::Rc};
+
     use super::*;
 
     const I8_VALUE: i8 = 8;
@@ -85,6 +167,49 @@ mod tests {
         assert_eq!(resource_manager.get(), Some(&I32_VALUE));
     }
 
+    #[test]
+    fn push_pop() {
+        let mut resource_manager = setup();
+        resource_manager.push(I32_VALUE);
+        resource_manager.push(I32_VALUE * 2);
+        assert_eq!(resource_manager.get(), Some(&(I32_VALUE * 2)));
+        assert_eq!(resource_manager.len(), 3);
+        assert_eq!(resource_manager.pop(), Some(I32_VALUE * 2));
+        assert_eq!(resource_manager.get(), Some(&I32_VALUE));
+        assert_eq!(resource_manager.pop(), Some(I32_VALUE));
+        assert!(!resource_manager.has::<i32>());
+        assert!(resource_manager.pop::<i32>().is_none());
+        resource_manager.push(I8_VALUE * 2);
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i16>()]
+        );
+        assert_eq!(resource_manager.pop(), Some(I8_VALUE * 2));
+        assert_eq!(resource_manager.get(), Some(&I8_VALUE));
+        resource_manager.push(I8_VALUE * 3);
+        resource_manager.clear();
+        resource_manager.push(I8_VALUE);
+        assert_eq!(resource_manager.pop(), Some(I8_VALUE));
+        assert!(resource_manager.pop::<i8>().is_none());
+    }
+
+    #[test]
+    fn push_remove() {
+        let mut resource_manager = setup();
+        resource_manager.push(I32_VALUE);
+        resource_manager.push(I32_VALUE * 2);
+        assert_eq!(resource_manager.remove(), Some(I32_VALUE * 2));
+        assert!(!resource_manager.has::<i32>());
+        assert!(resource_manager.pop::<i32>().is_none());
+        resource_manager.push(I32_VALUE * 3);
+        assert_eq!(resource_manager.pop(), Some(I32_VALUE * 3));
+        assert!(resource_manager.pop::<i32>().is_none());
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i16>()]
+        );
+    }
+
     #[test]
     fn has() {
         let resource_manager = setup();
@@ -126,11 +251,111 @@ mod tests {
         assert!(resource_manager.remove::<i32>().is_none());
     }
 
+    #[test]
+    fn take_restore() {
+        let mut resource_manager = setup();
+        assert!(resource_manager.insert(I32_VALUE).is_none());
+        resource_manager.push(I16_VALUE * 2);
+        assert!(resource_manager.take::<u8>().is_none());
+        let (value, index) = resource_manager.take::<i16>().unwrap();
+        assert_eq!((value, index), (I16_VALUE * 2, 1));
+        assert!(!resource_manager.has::<i16>());
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i32>()]
+        );
+        resource_manager.restore(value, index);
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i16>(), TypeId::of::<i32>()]
+        );
+        let (value, index) = resource_manager.take::<i16>().unwrap();
+        assert!(resource_manager.insert(I16_VALUE * 3).is_none());
+        resource_manager.restore(value + 1, index);
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i16>(), TypeId::of::<i32>()]
+        );
+        assert_eq!(resource_manager.len(), 3);
+        assert_eq!(resource_manager.pop(), Some(I16_VALUE * 2 + 1));
+        assert_eq!(resource_manager.get(), Some(&I16_VALUE));
+    }
+
+    #[test]
+    fn len() {
+        let mut resource_manager = setup();
+        assert_eq!(resource_manager.len(), 2);
+        assert!(!resource_manager.is_empty());
+        assert!(resource_manager.insert(I32_VALUE).is_none());
+        assert_eq!(resource_manager.len(), 3);
+        resource_manager.clear();
+        assert_eq!(resource_manager.len(), 0);
+        assert!(resource_manager.is_empty());
+    }
+
+    #[test]
+    fn drain() {
+        struct Guard(Rc<Cell<usize>>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut resource_manager = setup();
+        assert!(resource_manager.insert(Guard(Rc::clone(&drops))).is_none());
+        assert_eq!(resource_manager.drain().count(), 3);
+        assert_eq!(drops.get(), 1);
+        assert!(resource_manager.is_empty());
+        assert!(!resource_manager.has::<i8>());
+        assert!(resource_manager.insert(Guard(Rc::clone(&drops))).is_none());
+        let mut drained = resource_manager.drain().collect::<Vec<_>>();
+        assert_eq!(drops.get(), 1);
+        assert!(drained.pop().unwrap().downcast::<Guard>().is_ok());
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    fn order() {
+        let mut resource_manager = setup();
+        assert!(resource_manager.insert(I32_VALUE).is_none());
+        assert!(resource_manager.insert(I8_VALUE * 2).is_some());
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i16>(), TypeId::of::<i32>()]
+        );
+        assert!(resource_manager.remove::<i16>().is_some());
+        assert!(resource_manager.insert(I16_VALUE).is_none());
+        assert_eq!(
+            resource_manager.type_ids(),
+            [TypeId::of::<i8>(), TypeId::of::<i32>(), TypeId::of::<i16>()]
+        );
+        let mut drained = resource_manager.drain();
+        assert_eq!(
+            *drained.next().unwrap().downcast::<i8>().unwrap(),
+            I8_VALUE * 2
+        );
+        assert_eq!(
+            *drained.next().unwrap().downcast::<i32>().unwrap(),
+            I32_VALUE
+        );
+        assert_eq!(
+            *drained.next().unwrap().downcast::<i16>().unwrap(),
+            I16_VALUE
+        );
+        assert!(drained.next().is_none());
+        drop(drained);
+        assert!(resource_manager.type_ids().is_empty());
+    }
+
     #[test]
     fn clear() {
         let mut resource_manager = setup();
         resource_manager.clear();
         assert!(!resource_manager.has::<i8>());
         assert!(!resource_manager.has::<i16>());
+        assert!(resource_manager.type_ids().is_empty());
     }
 }
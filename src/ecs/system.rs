@@ -2,49 +2,184 @@ use super::{Entity, Manager, archetype::Archetype};
 
 pub trait SystemCallback = Fn(&mut Manager, &[Entity]);
 
-// pub(super) struct SystemBuilder<'a> {
-//     manager: &'a mut Manager<'a>,
-//     archetype: Archetype,
-//     antitype: Archetype,
-//     callback: Box<dyn SystemCallback>,
-// }
-//
-// impl SystemBuilder<'_> {
-//     fn with<T: 'static>(mut self) -> Self {
-//         self.archetype
-//             .add(self.manager.component_id_or_register::<T>());
-//         self
-//     }
-//
-//     fn without<T: 'static>(mut self) -> Self {
-//         self.antitype
-//             .add(self.manager.component_id_or_register::<T>());
-//         self
-//     }
-//
-//     fn build(self) -> System {
-//         System {
-//             archetype: self.archetype,
-//             antitype: self.antitype,
-//             sparse: Vec::new(),
-//             owners: Vec::new(),
-//             dense: Vec::new(),
-//             callback: self.callback,
-//         }
-//     }
-// }
-
-pub(super) struct System {
+pub trait ReadOnlySystemCallback = Fn(&Manager, &[Entity]);
+
+pub struct SystemBuilder<'a, C: ?Sized = dyn SystemCallback> {
+    manager: &'a mut Manager,
+    archetype: Archetype,
+    antitype: Archetype,
+    callbacks: Callbacks<C>,
+    is_run_if_nonempty: bool,
+}
+
+impl<'a> SystemBuilder<'a> {
+    #[must_use]
+    pub fn new<F: SystemCallback + 'static>(
+        manager: &'a mut Manager,
+        callback: F,
+    ) -> Self {
+        Self::with_callback(manager, Box::new(callback))
+    }
+
+    #[must_use]
+    pub fn on_added<F: SystemCallback + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.callbacks.on_added = Some(Box::new(callback));
+        self
+    }
+
+    #[must_use]
+    pub fn on_removed<F: SystemCallback + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.callbacks.on_removed = Some(Box::new(callback));
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> System {
+        self.build_with(Kind::Writer)
+    }
+}
+
+impl<'a> SystemBuilder<'a, dyn ReadOnlySystemCallback> {
+    #[must_use]
+    pub fn read_only<F: ReadOnlySystemCallback + 'static>(
+        manager: &'a mut Manager,
+        callback: F,
+    ) -> Self {
+        Self::with_callback(manager, Box::new(callback))
+    }
+
+    #[must_use]
+    pub fn on_added<F: ReadOnlySystemCallback + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.callbacks.on_added = Some(Box::new(callback));
+        self
+    }
+
+    #[must_use]
+    pub fn on_removed<F: ReadOnlySystemCallback + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.callbacks.on_removed = Some(Box::new(callback));
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> System {
+        self.build_with(Kind::Reader)
+    }
+}
+
+impl<'a, C: ?Sized> SystemBuilder<'a, C> {
+    #[must_use]
+    pub fn with<T: 'static>(mut self) -> Self {
+        self.archetype
+            .insert(self.manager.component_id_or_register::<T>());
+        self
+    }
+
+    #[must_use]
+    pub fn without<T: 'static>(mut self) -> Self {
+        self.antitype
+            .insert(self.manager.component_id_or_register::<T>());
+        self
+    }
+
+    #[must_use]
+    pub const fn run_if_nonempty(mut self) -> Self {
+        self.is_run_if_nonempty = true;
+        self
+    }
+
+    #[must_use]
+    const fn with_callback(manager: &'a mut Manager, callback: Box<C>) -> Self {
+        Self {
+            manager,
+            archetype: Archetype::new(),
+            antitype: Archetype::new(),
+            callbacks: Callbacks {
+                callback,
+                on_added: None,
+                on_removed: None,
+            },
+            is_run_if_nonempty: false,
+        }
+    }
+
+    #[must_use]
+    fn build_with(self, kind: fn(Callbacks<C>) -> Kind) -> System {
+        System {
+            archetype: self.archetype,
+            antitype: self.antitype,
+            sparse: Vec::new(),
+            dense: Vec::new(),
+            pending: Vec::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            kind: kind(self.callbacks),
+            is_run_if_nonempty: self.is_run_if_nonempty,
+        }
+    }
+}
+
+struct Callbacks<C: ?Sized> {
+    callback: Box<C>,
+    on_added: Option<Box<C>>,
+    on_removed: Option<Box<C>>,
+}
+
+impl<C: ?Sized> Callbacks<C> {
+    fn run<F: FnMut(&C, &[Entity])>(
+        &self,
+        added: &[Entity],
+        removed: &[Entity],
+        entities: Option<&[Entity]>,
+        mut call: F,
+    ) {
+        if let Some(on_removed) = &self.on_removed
+            && !removed.is_empty()
+        {
+            call(on_removed, removed);
+        }
+        if let Some(on_added) = &self.on_added
+            && !added.is_empty()
+        {
+            call(on_added, added);
+        }
+        if let Some(entities) = entities {
+            call(&self.callback, entities);
+        }
+    }
+}
+
+enum Kind {
+    Writer(Callbacks<dyn SystemCallback>),
+    Reader(Callbacks<dyn ReadOnlySystemCallback>),
+}
+
+pub struct System {
     archetype: Archetype,
     antitype: Archetype,
     sparse: Vec<Option<usize>>,
     dense: Vec<Entity>,
-    callback: Box<dyn SystemCallback>,
+    pending: Vec<Option<usize>>,
+    added: Vec<Entity>,
+    removed: Vec<Entity>,
+    kind: Kind,
+    is_run_if_nonempty: bool,
 }
 
 impl System {
     #[must_use]
-    pub fn new<F: SystemCallback + 'static>(
+    pub(super) fn new<F: SystemCallback + 'static>(
         archetype: Archetype,
         antitype: Archetype,
         callback: F,
@@ -54,38 +189,102 @@ impl System {
             antitype,
             sparse: Vec::new(),
             dense: Vec::new(),
-            callback: Box::new(callback),
+            pending: Vec::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+            kind: Kind::Writer(Callbacks {
+                callback: Box::new(callback),
+                on_added: None,
+                on_removed: None,
+            }),
+            is_run_if_nonempty: false,
         }
     }
 
-    pub fn evaluate(&mut self, entity: Entity, archetype: &Archetype) {
+    #[must_use]
+    pub const fn is_read_only(&self) -> bool {
+        matches!(self.kind, Kind::Reader(_))
+    }
+
+    /// Returns whether the systems may not run in the same stage. This assumes
+    /// that a system only touches the components of its own archetype, which
+    /// the callbacks have to uphold as they are handed the whole `Manager`.
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        !(self.is_read_only() && other.is_read_only())
+            && self.archetype.has_common_with(&other.archetype)
+    }
+
+    #[must_use]
+    pub const fn entity_count(&self) -> usize {
+        self.dense.len()
+    }
+
+    #[must_use]
+    pub fn added(&self) -> &[Entity] {
+        &self.added
+    }
+
+    #[must_use]
+    pub fn removed(&self) -> &[Entity] {
+        &self.removed
+    }
+
+    pub(super) fn evaluate(
+        &mut self,
+        entity: Entity,
+        archetype: &Archetype,
+        is_enabled: bool,
+    ) {
+        let is_matching = is_enabled
+            && self.archetype.is_subset_of(archetype)
+            && !self.antitype.has_common_with(archetype);
         if let Some(Some(index)) = self.sparse.get(entity.id()) {
-            if !self.archetype.is_subset_of(archetype)
-                || self.antitype.has_common_with(archetype)
-            {
+            if !is_matching {
                 self.remove_unchecked(*index);
             }
-        } else if self.archetype.is_subset_of(archetype)
-            && !self.antitype.has_common_with(archetype)
-        {
+        } else if is_matching {
             if self.sparse.len() <= entity.id() {
                 self.sparse.resize(entity.id() + 1, None);
+                self.pending.resize(entity.id() + 1, None);
             }
-            if self.sparse[entity.id()].is_none() {
-                self.sparse[entity.id()] = Some(self.dense.len());
-                self.dense.push(entity);
+            self.sparse[entity.id()] = Some(self.dense.len());
+            self.dense.push(entity);
+            if let Some(index) = self.pending[entity.id()] {
+                Self::unpend(&mut self.pending, &mut self.removed, index);
+            } else {
+                self.pending[entity.id()] = Some(self.added.len());
+                self.added.push(entity);
             }
         }
     }
 
-    pub fn remove(&mut self, entity: Entity) {
+    pub(super) fn remove(&mut self, entity: Entity) {
         if let Some(Some(index)) = self.sparse.get(entity.id()) {
             self.remove_unchecked(*index);
         }
     }
 
-    pub fn run(&self, manager: &mut Manager) {
-        (self.callback)(manager, &self.dense);
+    pub(super) fn run(&mut self, manager: &mut Manager) {
+        let entities = (!self.is_run_if_nonempty || !self.dense.is_empty())
+            .then_some(self.dense.as_slice());
+        match &self.kind {
+            Kind::Writer(callbacks) => callbacks.run(
+                &self.added,
+                &self.removed,
+                entities,
+                |callback, entities| callback(manager, entities),
+            ),
+            Kind::Reader(callbacks) => callbacks.run(
+                &self.added,
+                &self.removed,
+                entities,
+                |callback, entities| callback(manager, entities),
+            ),
+        }
+        for entity in self.added.drain(..).chain(self.removed.drain(..)) {
+            self.pending[entity.id()] = None;
+        }
     }
 
     fn remove_unchecked(&mut self, index: usize) {
@@ -95,8 +294,25 @@ impl System {
             let swapped = self.dense[index].id();
             self.sparse[swapped] = Some(index);
         }
-        self.dense.pop();
-        self.sparse[index] = None;
+        let removed = self.dense.pop().unwrap();
+        self.sparse[removed.id()] = None;
+        if let Some(index) = self.pending[removed.id()] {
+            Self::unpend(&mut self.pending, &mut self.added, index);
+        } else {
+            self.pending[removed.id()] = Some(self.removed.len());
+            self.removed.push(removed);
+        }
+    }
+
+    fn unpend(
+        pending: &mut [Option<usize>],
+        entities: &mut Vec<Entity>,
+        index: usize,
+    ) {
+        pending[entities.swap_remove(index).id()] = None;
+        if let Some(swapped) = entities.get(index) {
+            pending[swapped.id()] = Some(index);
+        }
     }
 }
 
@@ -112,15 +328,15 @@ mod tests {
     #[must_use]
     fn system_archetype() -> Archetype {
         let mut archetype = Archetype::new();
-        archetype.add(0);
-        archetype.add(1);
+        archetype.insert(0);
+        archetype.insert(1);
         archetype
     }
 
     #[must_use]
     fn system_antitype() -> Archetype {
         let mut archetype = Archetype::new();
-        archetype.add(2);
+        archetype.insert(2);
         archetype
     }
 
@@ -132,26 +348,26 @@ mod tests {
     #[must_use]
     fn conflicting_archetype() -> Archetype {
         let mut archetype = Archetype::new();
-        archetype.add(0);
-        archetype.add(1);
-        archetype.add(2);
+        archetype.insert(0);
+        archetype.insert(1);
+        archetype.insert(2);
         archetype
     }
 
     #[must_use]
     fn matching_archetype() -> Archetype {
         let mut archetype = Archetype::new();
-        archetype.add(0);
-        archetype.add(1);
+        archetype.insert(0);
+        archetype.insert(1);
         archetype
     }
 
     #[must_use]
     fn matching_supertype() -> Archetype {
         let mut archetype = Archetype::new();
-        archetype.add(0);
-        archetype.add(1);
-        archetype.add(3);
+        archetype.insert(0);
+        archetype.insert(1);
+        archetype.insert(3);
         archetype
     }
 
@@ -159,13 +375,108 @@ mod tests {
     fn setup<F: SystemCallback + 'static>(callback: F) -> System {
         let mut system =
             System::new(system_archetype(), system_antitype(), callback);
-        system.evaluate(ENTITY0, &empty_archetype());
-        system.evaluate(ENTITY1, &conflicting_archetype());
-        system.evaluate(ENTITY2, &matching_archetype());
-        system.evaluate(ENTITY3, &matching_supertype());
+        system.evaluate(ENTITY0, &empty_archetype(), true);
+        system.evaluate(ENTITY1, &conflicting_archetype(), true);
+        system.evaluate(ENTITY2, &matching_archetype(), true);
+        system.evaluate(ENTITY3, &matching_supertype(), true);
         system
     }
 
+    #[test]
+    fn builder() {
+        struct Position;
+        struct Velocity;
+        struct Frozen;
+
+        let mut manager = Manager::new();
+        let mut system = SystemBuilder::new(&mut manager, |_, entities| {
+            assert_eq!(entities, [ENTITY2]);
+        })
+        .with::<Position>()
+        .with::<Velocity>()
+        .without::<Frozen>()
+        .build();
+        let mut archetype = Archetype::new();
+        archetype.insert(manager.component_id_or_register::<Position>());
+        system.evaluate(ENTITY0, &archetype, true);
+        archetype.insert(manager.component_id_or_register::<Frozen>());
+        archetype.insert(manager.component_id_or_register::<Velocity>());
+        system.evaluate(ENTITY1, &archetype, true);
+        archetype.remove(manager.component_id_or_register::<Frozen>());
+        system.evaluate(ENTITY2, &archetype, true);
+        system.run(&mut manager);
+    }
+
+    #[test]
+    fn conflicts_with() {
+        struct Position;
+        struct Velocity;
+        struct Health;
+
+        let mut manager = Manager::new();
+        let position_reader = SystemBuilder::read_only(&mut manager, |_, _| {})
+            .with::<Position>()
+            .build();
+        let health_reader = SystemBuilder::read_only(&mut manager, |_, _| {})
+            .with::<Health>()
+            .build();
+        let position_writer = SystemBuilder::new(&mut manager, |_, _| {})
+            .with::<Position>()
+            .with::<Velocity>()
+            .build();
+        let velocity_writer = SystemBuilder::new(&mut manager, |_, _| {})
+            .with::<Velocity>()
+            .without::<Health>()
+            .build();
+        assert!(position_reader.is_read_only());
+        assert!(!position_writer.is_read_only());
+        assert!(!position_reader.conflicts_with(&health_reader));
+        assert!(!position_reader.conflicts_with(&position_reader));
+        assert!(position_reader.conflicts_with(&position_writer));
+        assert!(position_writer.conflicts_with(&position_reader));
+        assert!(position_writer.conflicts_with(&velocity_writer));
+        assert!(!velocity_writer.conflicts_with(&health_reader));
+        assert!(!position_reader.conflicts_with(&velocity_writer));
+    }
+
+    #[test]
+    fn run_if_nonempty() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Position;
+
+        let mut manager = Manager::new();
+        let runs = Rc::new(Cell::new(0));
+        let mut system = {
+            let runs = Rc::clone(&runs);
+            SystemBuilder::new(&mut manager, move |_, _| {
+                runs.set(runs.get() + 1);
+            })
+            .with::<Position>()
+            .run_if_nonempty()
+            .build()
+        };
+        system.run(&mut manager);
+        assert_eq!(runs.get(), 0);
+        let mut archetype = Archetype::new();
+        archetype.insert(manager.component_id_or_register::<Position>());
+        system.evaluate(ENTITY0, &archetype, true);
+        system.run(&mut manager);
+        assert_eq!(runs.get(), 1);
+        system.remove(ENTITY0);
+        system.run(&mut manager);
+        assert_eq!(runs.get(), 1);
+        let runs = Rc::new(Cell::new(0));
+        let mut always = {
+            let runs = Rc::clone(&runs);
+            System::new(empty_archetype(), empty_archetype(), move |_, _| {
+                runs.set(runs.get() + 1);
+            })
+        };
+        always.run(&mut manager);
+        assert_eq!(runs.get(), 1);
+    }
+
     #[test]
     fn evaluate_remove_run() {
         setup(|_, entities| {
@@ -176,6 +487,7 @@ mod tests {
             assert!(entities.contains(&ENTITY3));
         })
         .run(&mut Manager::new());
+        assert_eq!(setup(|_, _| {}).entity_count(), 2);
         let mut system = setup(|_, entities| {
             assert_eq!(entities.len(), 1);
             assert!(!entities.contains(&ENTITY0));
@@ -183,7 +495,7 @@ mod tests {
             assert!(!entities.contains(&ENTITY2));
             assert!(entities.contains(&ENTITY3));
         });
-        system.evaluate(ENTITY2, &empty_archetype());
+        system.evaluate(ENTITY2, &empty_archetype(), true);
         system.run(&mut Manager::new());
         let mut system = setup(|_, entities| {
             assert_eq!(entities.len(), 1);
@@ -192,11 +504,71 @@ mod tests {
             assert!(entities.contains(&ENTITY2));
             assert!(!entities.contains(&ENTITY3));
         });
-        system.evaluate(ENTITY3, &conflicting_archetype());
+        system.evaluate(ENTITY3, &conflicting_archetype(), true);
+        system.run(&mut Manager::new());
+        let mut system = setup(|_, entities| assert_eq!(entities, [ENTITY3]));
+        system.evaluate(ENTITY2, &matching_archetype(), false);
+        system.evaluate(ENTITY0, &matching_archetype(), false);
+        system.run(&mut Manager::new());
+        let mut system = setup(|_, entities| {
+            assert_eq!(entities, [ENTITY3, ENTITY2]);
+        });
+        system.evaluate(ENTITY2, &matching_archetype(), false);
+        system.evaluate(ENTITY2, &matching_archetype(), true);
         system.run(&mut Manager::new());
         let mut system = setup(|_, entities| assert_eq!(entities.len(), 0));
         system.remove(ENTITY2);
         system.remove(ENTITY3);
         system.run(&mut Manager::new());
+        assert_eq!(system.entity_count(), 0);
+    }
+
+    #[test]
+    fn added_removed() {
+        let mut system = setup(|_, _| {});
+        assert_eq!(system.added(), [ENTITY2, ENTITY3]);
+        assert!(system.removed().is_empty());
+        system.run(&mut Manager::new());
+        assert!(system.added().is_empty());
+        system.evaluate(ENTITY0, &matching_archetype(), true);
+        assert_eq!(system.added(), [ENTITY0]);
+        system.run(&mut Manager::new());
+        system.evaluate(ENTITY0, &empty_archetype(), true);
+        assert!(system.added().is_empty());
+        assert_eq!(system.removed(), [ENTITY0]);
+        system.evaluate(ENTITY0, &matching_archetype(), true);
+        assert!(system.added().is_empty());
+        assert!(system.removed().is_empty());
+        system.evaluate(ENTITY1, &matching_archetype(), true);
+        system.remove(ENTITY1);
+        assert!(system.added().is_empty());
+        assert!(system.removed().is_empty());
+    }
+
+    #[test]
+    fn pending() {
+        let mut system = setup(|_, _| {});
+        system.evaluate(ENTITY0, &matching_archetype(), true);
+        system.evaluate(ENTITY1, &matching_archetype(), true);
+        system.remove(ENTITY2);
+        assert_eq!(system.added(), [ENTITY1, ENTITY3, ENTITY0]);
+        system.remove(ENTITY0);
+        system.evaluate(ENTITY2, &matching_archetype(), true);
+        assert_eq!(system.added(), [ENTITY1, ENTITY3, ENTITY2]);
+        system.run(&mut Manager::new());
+        system.remove(ENTITY1);
+        system.remove(ENTITY3);
+        system.remove(ENTITY2);
+        assert_eq!(system.removed(), [ENTITY1, ENTITY3, ENTITY2]);
+        system.evaluate(ENTITY1, &matching_archetype(), true);
+        assert_eq!(system.removed(), [ENTITY2, ENTITY3]);
+        system.evaluate(ENTITY2, &matching_archetype(), true);
+        system.evaluate(ENTITY0, &matching_archetype(), true);
+        assert_eq!(system.removed(), [ENTITY3]);
+        assert_eq!(system.added(), [ENTITY0]);
+        system.run(&mut Manager::new());
+        system.remove(ENTITY0);
+        assert!(system.added().is_empty());
+        assert_eq!(system.removed(), [ENTITY0]);
     }
 }
@@ -0,0 +1,186 @@
+use super::{Entity, Manager};
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct DeltaTime(pub f32);
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Timer {
+    duration: f32,
+    elapsed: f32,
+    is_repeating: bool,
+    is_fired: bool,
+    is_finished: bool,
+}
+
+impl Timer {
+    #[must_use]
+    pub const fn new(duration: f32, is_repeating: bool) -> Self {
+        Self {
+            duration,
+            elapsed: 0.0,
+            is_repeating,
+            is_fired: false,
+            is_finished: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    #[must_use]
+    pub const fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    #[must_use]
+    pub const fn is_repeating(&self) -> bool {
+        self.is_repeating
+    }
+
+    #[must_use]
+    pub const fn is_fired(&self) -> bool {
+        self.is_fired
+    }
+
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    pub fn tick(&mut self, dt: f32) -> bool {
+        self.is_fired = if self.is_finished() {
+            false
+        } else if self.is_repeating {
+            self.elapsed += dt;
+            let is_fired = self.duration <= self.elapsed;
+            if self.duration <= 0.0 {
+                self.elapsed = 0.0;
+            } else {
+                self.elapsed %= self.duration;
+            }
+            is_fired
+        } else {
+            self.elapsed = (self.elapsed + dt).min(self.duration);
+            self.is_finished = self.duration <= self.elapsed;
+            self.is_finished
+        };
+        self.is_fired
+    }
+
+    pub const fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.is_fired = false;
+        self.is_finished = false;
+    }
+}
+
+pub fn tick_timers(manager: &mut Manager, entities: &[Entity]) {
+    let dt = manager.resource::<DeltaTime>().map_or(0.0, |dt| dt.0);
+    for entity in entities {
+        if let Some(timer) = manager.component_mut::<Timer>(*entity) {
+            timer.tick(dt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::SystemBuilder;
+
+    #[test]
+    fn one_shot() {
+        let mut timer = Timer::new(1.0, false);
+        assert!(!timer.tick(0.5));
+        approx::assert_relative_eq!(timer.elapsed(), 0.5);
+        assert!(timer.tick(0.75));
+        assert!(timer.is_fired());
+        assert!(timer.is_finished());
+        approx::assert_relative_eq!(timer.elapsed(), 1.0);
+        assert!(!timer.tick(1.0));
+        assert!(!timer.is_fired());
+        timer.reset();
+        assert!(!timer.is_finished());
+        assert!(timer.tick(1.0));
+    }
+
+    #[test]
+    fn zero_duration() {
+        let mut timer = Timer::new(0.0, false);
+        assert!(!timer.is_finished());
+        assert!(timer.tick(0.0));
+        assert!(timer.is_fired());
+        assert!(timer.is_finished());
+        assert!(!timer.tick(0.5));
+        assert!(!timer.is_fired());
+        timer.reset();
+        assert!(timer.tick(0.5));
+        approx::assert_relative_eq!(timer.elapsed(), 0.0);
+    }
+
+    #[test]
+    fn repeating() {
+        let mut timer = Timer::new(0.5, true);
+        let fired = (0..10).filter(|_| timer.tick(0.125)).count();
+        assert_eq!(fired, 2);
+        approx::assert_relative_eq!(timer.elapsed(), 0.25);
+        assert!(!timer.is_finished());
+        assert!(timer.tick(0.25));
+        approx::assert_relative_eq!(timer.elapsed(), 0.0);
+        assert!(!timer.tick(0.375));
+        assert!(!timer.is_fired());
+    }
+
+    #[test]
+    fn partial_progress() {
+        let mut timer = Timer::new(2.0, false);
+        for _ in 0..3 {
+            assert!(!timer.tick(0.5));
+        }
+        assert!(!timer.is_finished());
+        approx::assert_relative_eq!(timer.elapsed(), 1.5);
+        let mut timer = Timer::new(2.0, true);
+        assert!(!timer.tick(1.999));
+    }
+
+    #[test]
+    fn tick_timers() {
+        let mut manager = Manager::new();
+        let entity0 = manager.spawn_entity();
+        let entity1 = manager.spawn_entity();
+        assert!(
+            manager
+                .add_component(entity0, Timer::new(1.0, false))
+                .is_ok()
+        );
+        assert!(
+            manager
+                .add_component(entity1, Timer::new(0.5, true))
+                .is_ok()
+        );
+        let mut system = SystemBuilder::new(&mut manager, super::tick_timers)
+            .with::<Timer>()
+            .build();
+        let _ = manager.insert_resource(DeltaTime(0.5));
+        system.evaluate(
+            entity0,
+            manager.entity_data(entity0).unwrap().archetype(),
+            true,
+        );
+        system.evaluate(
+            entity1,
+            manager.entity_data(entity1).unwrap().archetype(),
+            true,
+        );
+        system.run(&mut manager);
+        assert!(!manager.component::<Timer>(entity0).unwrap().is_fired());
+        assert!(manager.component::<Timer>(entity1).unwrap().is_fired());
+        system.run(&mut manager);
+        assert!(manager.component::<Timer>(entity0).unwrap().is_fired());
+        let _ = manager.remove_resource::<DeltaTime>();
+        system.run(&mut manager);
+        assert!(!manager.component::<Timer>(entity1).unwrap().is_fired());
+    }
+}
@@ -6,20 +6,333 @@ pub struct World {
 }
 
 impl World {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            manager: Manager::new(),
+            systems: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_capacity(entities: usize) -> Self {
+        Self {
+            manager: Manager::with_capacity(entities),
+            systems: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn manager(&self) -> &Manager {
+        &self.manager
+    }
+
+    #[must_use]
+    pub const fn manager_mut(&mut self) -> &mut Manager {
+        &mut self.manager
+    }
+
+    pub fn add_system(&mut self, mut system: System) {
+        for entity_data in self.manager.all_entity_data() {
+            system.evaluate(
+                entity_data.owner(),
+                entity_data.archetype(),
+                entity_data.is_enabled(),
+            );
+        }
+        self.systems.push(system);
+    }
+
     pub fn run(&mut self) {
         for i in 0..self.systems.len() {
+            self.evaluate_dirty();
             self.systems[i].run(&mut self.manager);
-            while let Some(entity) = self.manager.poll_dirty() {
-                if let Some(archetype) = self.manager.entity_archetype(entity) {
-                    for system in &mut self.systems {
-                        system.evaluate(entity, archetype);
-                    }
-                } else {
-                    for system in &mut self.systems {
-                        system.remove(entity);
-                    }
+        }
+    }
+
+    #[must_use]
+    pub fn system_stats(&self) -> Vec<(usize, usize)> {
+        self.systems
+            .iter()
+            .map(System::entity_count)
+            .enumerate()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn parallel_plan(&self) -> Vec<Vec<usize>> {
+        let mut plan: Vec<Vec<usize>> = Vec::new();
+        for (i, system) in self.systems.iter().enumerate() {
+            match plan.last_mut() {
+                Some(stage)
+                    if stage
+                        .iter()
+                        .all(|j| !self.systems[*j].conflicts_with(system)) =>
+                {
+                    stage.push(i);
                 }
+                _ => plan.push(vec![i]),
             }
         }
+        plan
+    }
+
+    fn evaluate_dirty(&mut self) {
+        while let Some(entity) = self.manager.poll_dirty() {
+            if let Some(entity_data) = self.manager.entity_data(entity) {
+                for system in &mut self.systems {
+                    system.evaluate(
+                        entity,
+                        entity_data.archetype(),
+                        entity_data.is_enabled(),
+                    );
+                }
+            } else {
+                for system in &mut self.systems {
+                    system.remove(entity);
+                }
+            }
+        }
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::ecs::{AddError, Entity, SystemBuilder};
+
+    struct Marker;
+
+    #[derive(Debug, PartialEq)]
+    struct Health;
+
+    #[must_use]
+    fn recording_system(
+        world: &mut World,
+        runs: &Rc<RefCell<Vec<Vec<Entity>>>>,
+    ) -> System {
+        let runs = Rc::clone(runs);
+        SystemBuilder::new(world.manager_mut(), move |_, entities| {
+            runs.borrow_mut().push(entities.to_vec());
+        })
+        .with::<Health>()
+        .build()
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut world = World::with_capacity(16);
+        let entity = world.manager_mut().spawn_entity();
+        assert!(world.manager_mut().add_component(entity, Health).is_ok());
+        world.run();
+        assert!(world.system_stats().is_empty());
+        assert_eq!(world.manager().component(entity), Some(&Health));
+    }
+
+    #[test]
+    fn add_system() {
+        let mut world = World::default();
+        let entity0 = world.manager_mut().spawn_entity();
+        let entity1 = world.manager_mut().spawn_entity();
+        assert!(world.manager_mut().add_component(entity0, Health).is_ok());
+        world.run();
+        let runs = Rc::new(RefCell::new(Vec::<Vec<Entity>>::new()));
+        let health_system = recording_system(&mut world, &runs);
+        world.add_system(health_system);
+        assert_eq!(world.system_stats(), [(0, 1)]);
+        assert!(world.manager_mut().add_component(entity1, Health).is_ok());
+        world.run();
+        assert_eq!(*runs.borrow(), [vec![entity0, entity1]]);
+    }
+
+    #[test]
+    fn run() {
+        let mut world = World::new();
+        let entity0 = world.manager_mut().spawn_entity();
+        let entity1 = world.manager_mut().spawn_entity();
+        assert!(world.manager_mut().add_component(entity0, Marker).is_ok());
+        let marker_system =
+            SystemBuilder::new(world.manager_mut(), |manager, entities| {
+                for entity in entities {
+                    assert!(!matches!(
+                        manager.add_component(*entity, Health),
+                        Err(AddError::Dead(_))
+                    ));
+                }
+            })
+            .with::<Marker>()
+            .build();
+        world.add_system(marker_system);
+        let runs = Rc::new(RefCell::new(Vec::<Vec<Entity>>::new()));
+        let health_system = recording_system(&mut world, &runs);
+        world.add_system(health_system);
+        world.run();
+        assert_eq!(*runs.borrow(), [vec![entity0]]);
+        world.run();
+        assert_eq!(*runs.borrow(), [vec![entity0], vec![entity0]]);
+        world.manager_mut().remove_component::<Health>(entity0);
+        world.manager_mut().remove_component::<Marker>(entity0);
+        assert!(world.manager_mut().add_component(entity1, Health).is_ok());
+        world.run();
+        assert_eq!(
+            *runs.borrow(),
+            [vec![entity0], vec![entity0], vec![entity1]]
+        );
+        world.manager_mut().destroy_entity(entity1);
+        world.run();
+        assert_eq!(
+            *runs.borrow(),
+            [vec![entity0], vec![entity0], vec![entity1], Vec::new()]
+        );
+    }
+
+    #[test]
+    fn set_enabled() {
+        let mut world = World::new();
+        let entity0 = world.manager_mut().spawn_entity();
+        let entity1 = world.manager_mut().spawn_entity();
+        assert!(world.manager_mut().add_component(entity0, Health).is_ok());
+        assert!(world.manager_mut().add_component(entity1, Health).is_ok());
+        let runs = Rc::new(RefCell::new(Vec::<Vec<Entity>>::new()));
+        let health_system = recording_system(&mut world, &runs);
+        world.add_system(health_system);
+        assert!(world.manager_mut().set_enabled(entity0, false).is_ok());
+        world.run();
+        assert_eq!(world.manager().is_entity_enabled(entity0), Some(false));
+        assert_eq!(world.manager().component(entity0), Some(&Health));
+        world.run();
+        assert!(world.manager_mut().set_enabled(entity0, true).is_ok());
+        world.run();
+        assert_eq!(
+            *runs.borrow(),
+            [vec![entity1], vec![entity1], vec![entity1, entity0]]
+        );
+        world.manager_mut().destroy_entity(entity1);
+        assert!(world.manager_mut().set_enabled(entity1, false).is_err());
+        assert!(world.manager().is_entity_enabled(entity1).is_none());
+    }
+
+    #[test]
+    fn system_stats() {
+        let mut world = World::new();
+        let health_system = SystemBuilder::new(world.manager_mut(), |_, _| {})
+            .with::<Health>()
+            .build();
+        world.add_system(health_system);
+        let marker_system = SystemBuilder::new(world.manager_mut(), |_, _| {})
+            .with::<Marker>()
+            .with::<Health>()
+            .build();
+        world.add_system(marker_system);
+        let entity0 = world.manager_mut().spawn_entity();
+        let entity1 = world.manager_mut().spawn_entity();
+        let _ = world.manager_mut().spawn_entity();
+        assert!(world.manager_mut().add_component(entity0, Marker).is_ok());
+        assert!(world.manager_mut().add_component(entity0, Health).is_ok());
+        assert!(world.manager_mut().add_component(entity1, Health).is_ok());
+        assert_eq!(world.system_stats(), [(0, 0), (1, 0)]);
+        world.run();
+        assert_eq!(world.system_stats(), [(0, 2), (1, 1)]);
+        world.manager_mut().destroy_entity(entity0);
+        world.run();
+        assert_eq!(world.system_stats(), [(0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn on_added_on_removed() {
+        let mut world = World::new();
+        let entity0 = world.manager_mut().spawn_entity();
+        let _ = world.manager_mut().spawn_entity();
+        let events = Rc::new(RefCell::new(Vec::<(bool, Vec<Entity>)>::new()));
+        let health_system = {
+            let added = Rc::clone(&events);
+            let removed = Rc::clone(&events);
+            SystemBuilder::new(world.manager_mut(), |_, _| {})
+                .with::<Health>()
+                .on_added(move |_, entities| {
+                    added.borrow_mut().push((true, entities.to_vec()));
+                })
+                .on_removed(move |_, entities| {
+                    removed.borrow_mut().push((false, entities.to_vec()));
+                })
+                .build()
+        };
+        world.add_system(health_system);
+        world.run();
+        assert!(events.borrow().is_empty());
+        assert!(world.manager_mut().add_component(entity0, Health).is_ok());
+        world.run();
+        world.run();
+        assert_eq!(*events.borrow(), [(true, vec![entity0])]);
+        world.manager_mut().remove_component::<Health>(entity0);
+        world.run();
+        assert_eq!(
+            *events.borrow(),
+            [(true, vec![entity0]), (false, vec![entity0])]
+        );
+    }
+
+    #[test]
+    fn read_only() {
+        let mut world = World::new();
+        let entity = world.manager_mut().spawn_entity();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let health_reader = {
+            let added = Rc::clone(&events);
+            let runs = Rc::clone(&events);
+            SystemBuilder::read_only(world.manager_mut(), move |manager, _| {
+                runs.borrow_mut()
+                    .push(manager.all_component::<Health>().len());
+            })
+            .with::<Health>()
+            .on_added(move |manager, entities| {
+                assert!(manager.has_component::<Health>(entities[0]));
+                added.borrow_mut().push(0);
+            })
+            .build()
+        };
+        assert!(health_reader.is_read_only());
+        world.add_system(health_reader);
+        assert!(world.manager_mut().add_component(entity, Health).is_ok());
+        world.run();
+        world.run();
+        assert_eq!(*events.borrow(), [0, 1, 1]);
+    }
+
+    #[test]
+    fn parallel_plan() {
+        let mut world = World::new();
+        let marker_reader =
+            SystemBuilder::read_only(world.manager_mut(), |_, _| {})
+                .with::<Marker>()
+                .build();
+        world.add_system(marker_reader);
+        let health_reader =
+            SystemBuilder::read_only(world.manager_mut(), |_, _| {})
+                .with::<Health>()
+                .build();
+        world.add_system(health_reader);
+        let health_writer = SystemBuilder::new(world.manager_mut(), |_, _| {})
+            .with::<Health>()
+            .build();
+        world.add_system(health_writer);
+        let other_health_writer =
+            SystemBuilder::new(world.manager_mut(), |_, _| {})
+                .with::<Health>()
+                .build();
+        world.add_system(other_health_writer);
+        let marker_writer = SystemBuilder::new(world.manager_mut(), |_, _| {})
+            .with::<Marker>()
+            .build();
+        world.add_system(marker_writer);
+        assert_eq!(world.parallel_plan(), [vec![0, 1], vec![2], vec![3, 4]]);
     }
 }
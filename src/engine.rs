@@ -1,4 +1,10 @@
 pub use self::context::{Context, ContextData};
 
+/// `Flywheel` [log] module.
+pub mod log;
+
+/// `Flywheel` [`message_box`] module.
+pub mod message_box;
+
 mod context;
 mod ffi;
@@ -1,11 +1,12 @@
 use core::{
+    any::Any,
     ffi::CStr,
     ptr::null_mut,
     sync::atomic::{AtomicBool, Ordering},
 };
 use std::{ffi::CString, panic};
 
-use super::ffi::sdl3::{sdl_error, sdl_init, sdl_messagebox};
+use super::ffi::sdl3::{sdl_error, sdl_init, sdl_messagebox, sdl_version};
 
 static IS_CONTEXT_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
@@ -67,7 +68,9 @@ pub struct ContextData<'a> {
 ///     r#type: "game",
 /// }).unwrap();
 /// ```
-pub struct Context;
+pub struct Context {
+    user_data: Option<Box<dyn Any>>,
+}
 
 impl Context {
     /// Constructs a new application `Context`.
@@ -154,7 +157,7 @@ impl Context {
                 .to_string_lossy()
                 .to_string());
         }
-        Ok(Self)
+        Ok(Self { user_data: None })
     }
 
     /// Returns the name of the application.
@@ -241,6 +244,37 @@ impl Context {
         .to_string()
     }
 
+    /// Stores the given user data in the `Context`, replacing any previous one.
+    pub fn set_user_data<T: 'static>(&mut self, user_data: T) {
+        self.user_data = Some(Box::new(user_data));
+    }
+
+    /// Returns a reference to the stored user data, or `None` if there is none
+    /// or it is not of type `T`.
+    #[must_use]
+    pub fn user_data<T: 'static>(&self) -> Option<&T> {
+        self.user_data.as_ref()?.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the stored user data, or `None` if there
+    /// is none or it is not of type `T`.
+    #[must_use]
+    pub fn user_data_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.user_data.as_mut()?.downcast_mut()
+    }
+
+    /// Returns the major, minor, micro version of the linked SDL library.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn sdl_version() -> (u16, u16, u16) {
+        let version = unsafe { sdl_version::SDL_GetVersion() };
+        (
+            (version / 1_000_000) as u16,
+            (version / 1_000 % 1_000) as u16,
+            (version % 1_000) as u16,
+        )
+    }
+
     fn set_panic_hook(title: String) {
         panic::set_hook(Box::new(move |p| {
             eprintln!("{p}");
@@ -270,6 +304,7 @@ impl Context {
 
 impl Drop for Context {
     fn drop(&mut self) {
+        self.user_data = None;
         unsafe {
             sdl_init::SDL_Quit();
         }
@@ -281,8 +316,12 @@ impl !Send for Context {}
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+
     use super::*;
 
+    static CONTEXT_LOCK: Mutex<()> = Mutex::new(());
+
     #[test]
     fn new() {
         const CONTEXT_DATA: ContextData = ContextData {
@@ -294,6 +333,7 @@ mod tests {
             url: "game.example.com",
             r#type: "game",
         };
+        let _lock = CONTEXT_LOCK.lock().unwrap();
         let context = Context::new(&CONTEXT_DATA).unwrap();
         assert_eq!(context.name(), CONTEXT_DATA.name);
         assert_eq!(context.version(), CONTEXT_DATA.version);
@@ -303,4 +343,99 @@ mod tests {
         assert_eq!(context.url(), CONTEXT_DATA.url);
         assert_eq!(context.r#type(), CONTEXT_DATA.r#type);
     }
+
+    #[test]
+    fn new_after_drop() {
+        const CONTEXT_DATA: ContextData = ContextData {
+            name: "Game",
+            version: "0.1.0",
+            identifier: "com.example.game",
+            creator: "Example Studios",
+            copyright: "Copyright (C) 2025 Example Studios",
+            url: "game.example.com",
+            r#type: "game",
+        };
+        const OTHER_CONTEXT_DATA: ContextData = ContextData {
+            name: "Other Game",
+            version: "0.2.0",
+            identifier: "com.example.other",
+            creator: "Other Studios",
+            copyright: "Copyright (C) 2025 Other Studios",
+            url: "other.example.com",
+            r#type: "application",
+        };
+        let _lock = CONTEXT_LOCK.lock().unwrap();
+        let context = Context::new(&CONTEXT_DATA).unwrap();
+        assert!(Context::new(&OTHER_CONTEXT_DATA).is_err());
+        assert_eq!(context.name(), CONTEXT_DATA.name);
+        drop(context);
+        let context = Context::new(&OTHER_CONTEXT_DATA).unwrap();
+        assert_eq!(context.name(), OTHER_CONTEXT_DATA.name);
+        assert_eq!(context.version(), OTHER_CONTEXT_DATA.version);
+        assert_eq!(context.identifier(), OTHER_CONTEXT_DATA.identifier);
+        assert_eq!(context.creator(), OTHER_CONTEXT_DATA.creator);
+        assert_eq!(context.copyright(), OTHER_CONTEXT_DATA.copyright);
+        assert_eq!(context.url(), OTHER_CONTEXT_DATA.url);
+        assert_eq!(context.r#type(), OTHER_CONTEXT_DATA.r#type);
+    }
+
+    #[test]
+    fn user_data() {
+        const CONTEXT_DATA: ContextData = ContextData {
+            name: "Game",
+            version: "0.1.0",
+            identifier: "com.example.game",
+            creator: "Example Studios",
+            copyright: "Copyright (C) 2025 Example Studios",
+            url: "game.example.com",
+            r#type: "game",
+        };
+        let _lock = CONTEXT_LOCK.lock().unwrap();
+        let mut context = Context::new(&CONTEXT_DATA).unwrap();
+        assert!(context.user_data::<u32>().is_none());
+        context.set_user_data(7_u32);
+        assert_eq!(context.user_data::<u32>(), Some(&7));
+        assert!(context.user_data::<i32>().is_none());
+        *context.user_data_mut::<u32>().unwrap() += 1;
+        assert_eq!(context.user_data::<u32>(), Some(&8));
+        assert!(context.user_data_mut::<String>().is_none());
+        context.set_user_data(String::from("state"));
+        assert!(context.user_data::<u32>().is_none());
+        assert_eq!(context.user_data::<String>().unwrap(), "state");
+    }
+
+    #[test]
+    fn drop_user_data() {
+        struct Probe(Arc<AtomicBool>);
+
+        impl Drop for Probe {
+            fn drop(&mut self) {
+                self.0.store(
+                    IS_CONTEXT_INITIALIZED.load(Ordering::SeqCst),
+                    Ordering::SeqCst,
+                );
+            }
+        }
+
+        const CONTEXT_DATA: ContextData = ContextData {
+            name: "Game",
+            version: "0.1.0",
+            identifier: "com.example.game",
+            creator: "Example Studios",
+            copyright: "Copyright (C) 2025 Example Studios",
+            url: "game.example.com",
+            r#type: "game",
+        };
+        let _lock = CONTEXT_LOCK.lock().unwrap();
+        let mut context = Context::new(&CONTEXT_DATA).unwrap();
+        let is_initialized = Arc::new(AtomicBool::new(false));
+        context.set_user_data(Probe(Arc::clone(&is_initialized)));
+        drop(context);
+        assert!(is_initialized.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn sdl_version() {
+        assert_eq!(Context::sdl_version().0, 3);
+    }
 }
@@ -49,12 +49,73 @@ pub mod sdl_init {
     }
 }
 
+pub mod sdl_log {
+    use core::ffi::{c_char, c_int, c_uint};
+
+    pub const SDL_LOG_CATEGORY_APPLICATION: c_int = 0;
+
+    pub const SDL_LOG_PRIORITY_INFO: c_uint = 4;
+    pub const SDL_LOG_PRIORITY_WARN: c_uint = 5;
+    pub const SDL_LOG_PRIORITY_ERROR: c_uint = 6;
+
+    #[link(name = "SDL3")]
+    unsafe extern "C" {
+        pub fn SDL_LogMessage(
+            category: c_int,
+            priority: c_uint,
+            fmt: *const c_char,
+            ...
+        );
+    }
+}
+
 pub mod sdl_messagebox {
-    use core::ffi::{c_char, c_uint};
+    use core::ffi::{c_char, c_int, c_uint};
 
     use super::sdl_video::SdlWindow;
 
     pub const SDL_MESSAGEBOX_ERROR: c_uint = 0x0000_0010;
+    pub const SDL_MESSAGEBOX_WARNING: c_uint = 0x0000_0020;
+    pub const SDL_MESSAGEBOX_INFORMATION: c_uint = 0x0000_0040;
+
+    pub const SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT: c_uint = 0x0000_0001;
+    pub const SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT: c_uint = 0x0000_0002;
+
+    pub const SDL_MESSAGEBOX_COLOR_COUNT: usize = 5;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct SdlMessageBoxButtonData {
+        pub flags: c_uint,
+        pub button_id: c_int,
+        pub text: *const c_char,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+    pub struct SdlMessageBoxColor {
+        pub r: u8,
+        pub g: u8,
+        pub b: u8,
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+    pub struct SdlMessageBoxColorScheme {
+        pub colors: [SdlMessageBoxColor; SDL_MESSAGEBOX_COLOR_COUNT],
+    }
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct SdlMessageBoxData {
+        pub flags: c_uint,
+        pub window: *mut SdlWindow,
+        pub title: *const c_char,
+        pub message: *const c_char,
+        pub num_buttons: c_int,
+        pub buttons: *const SdlMessageBoxButtonData,
+        pub color_scheme: *const SdlMessageBoxColorScheme,
+    }
 
     #[link(name = "SDL3")]
     unsafe extern "C" {
@@ -65,6 +126,22 @@ pub mod sdl_messagebox {
             message: *const c_char,
             window: *mut SdlWindow,
         ) -> bool;
+
+        #[must_use]
+        pub fn SDL_ShowMessageBox(
+            messageboxdata: *const SdlMessageBoxData,
+            buttonid: *mut c_int,
+        ) -> bool;
+    }
+}
+
+pub mod sdl_version {
+    use core::ffi::c_int;
+
+    #[link(name = "SDL3")]
+    unsafe extern "C" {
+        #[must_use]
+        pub fn SDL_GetVersion() -> c_int;
     }
 }
 
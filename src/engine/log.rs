@@ -0,0 +1,81 @@
+use core::ffi::c_uint;
+use std::ffi::CString;
+
+use super::ffi::sdl3::sdl_log;
+
+/// Severity `Level` of a log message.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Level {
+    /// Informational message.
+    Info,
+    /// Warning message.
+    Warn,
+    /// Error message.
+    Error,
+}
+
+impl Level {
+    #[must_use]
+    const fn priority(self) -> c_uint {
+        match self {
+            Self::Info => sdl_log::SDL_LOG_PRIORITY_INFO,
+            Self::Warn => sdl_log::SDL_LOG_PRIORITY_WARN,
+            Self::Error => sdl_log::SDL_LOG_PRIORITY_ERROR,
+        }
+    }
+}
+
+/// Logs a message with the given `Level` through the platform's console.
+pub fn message(level: Level, message: &str) {
+    let message = into_c_string(message);
+    unsafe {
+        sdl_log::SDL_LogMessage(
+            sdl_log::SDL_LOG_CATEGORY_APPLICATION,
+            level.priority(),
+            c"%s".as_ptr(),
+            message.as_ptr(),
+        );
+    }
+}
+
+/// Logs an informational message.
+pub fn info(message: &str) {
+    self::message(Level::Info, message);
+}
+
+/// Logs a warning message.
+pub fn warn(message: &str) {
+    self::message(Level::Warn, message);
+}
+
+/// Logs an error message.
+pub fn error(message: &str) {
+    self::message(Level::Error, message);
+}
+
+#[must_use]
+fn into_c_string(message: &str) -> CString {
+    CString::new(message.replace('\0', "")).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority() {
+        assert_eq!(Level::Info.priority(), sdl_log::SDL_LOG_PRIORITY_INFO);
+        assert_eq!(Level::Warn.priority(), sdl_log::SDL_LOG_PRIORITY_WARN);
+        assert_eq!(Level::Error.priority(), sdl_log::SDL_LOG_PRIORITY_ERROR);
+    }
+
+    #[test]
+    fn into_c_string() {
+        assert_eq!(super::into_c_string("message").as_c_str(), c"message");
+        assert_eq!(
+            super::into_c_string("100% \0done").as_c_str(),
+            c"100% done"
+        );
+        assert_eq!(super::into_c_string("").as_c_str(), c"");
+    }
+}
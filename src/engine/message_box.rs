@@ -0,0 +1,309 @@
+use core::{
+    ffi::{CStr, c_int, c_uint},
+    ptr::{self, null_mut},
+};
+use std::ffi::CString;
+
+use super::ffi::sdl3::{sdl_error, sdl_messagebox};
+
+/// Severity `Kind` of a `MessageBox`.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Kind {
+    /// Error dialog.
+    Error,
+    /// Warning dialog.
+    Warning,
+    /// Informational dialog.
+    Information,
+}
+
+impl Kind {
+    #[must_use]
+    const fn flags(self) -> c_uint {
+        match self {
+            Self::Error => sdl_messagebox::SDL_MESSAGEBOX_ERROR,
+            Self::Warning => sdl_messagebox::SDL_MESSAGEBOX_WARNING,
+            Self::Information => sdl_messagebox::SDL_MESSAGEBOX_INFORMATION,
+        }
+    }
+}
+
+/// RGB `Colors` of a `MessageBox`.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Colors {
+    /// Background of the dialog.
+    pub background: (u8, u8, u8),
+    /// Text of the message.
+    pub text: (u8, u8, u8),
+    /// Border of the buttons.
+    pub button_border: (u8, u8, u8),
+    /// Background of the buttons.
+    pub button_background: (u8, u8, u8),
+    /// Background of the selected button.
+    pub button_selected: (u8, u8, u8),
+}
+
+impl Colors {
+    #[must_use]
+    const fn color_scheme(self) -> sdl_messagebox::SdlMessageBoxColorScheme {
+        const fn color(
+            (r, g, b): (u8, u8, u8),
+        ) -> sdl_messagebox::SdlMessageBoxColor {
+            sdl_messagebox::SdlMessageBoxColor { r, g, b }
+        }
+
+        sdl_messagebox::SdlMessageBoxColorScheme {
+            colors: [
+                color(self.background),
+                color(self.text),
+                color(self.button_border),
+                color(self.button_background),
+                color(self.button_selected),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Button {
+    flags: c_uint,
+    id: i32,
+    text: String,
+}
+
+/// Modal `MessageBox` with custom buttons.
+///
+/// # Examples
+///
+/// ```no_run
+/// use flywheel::engine::message_box::{Kind, MessageBox};
+///
+/// let pressed = MessageBox::new("Game", "Save before quitting?")
+///     .with_kind(Kind::Warning)
+///     .with_return_button(0, "Save")
+///     .with_button(1, "Discard")
+///     .with_escape_button(2, "Cancel")
+///     .show()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageBox {
+    kind: Kind,
+    title: String,
+    message: String,
+    buttons: Vec<Button>,
+    colors: Option<Colors>,
+}
+
+impl MessageBox {
+    /// Constructs a new informational `MessageBox` without buttons.
+    #[must_use]
+    pub fn new(title: &str, message: &str) -> Self {
+        Self {
+            kind: Kind::Information,
+            title: title.to_owned(),
+            message: message.to_owned(),
+            buttons: Vec::new(),
+            colors: None,
+        }
+    }
+
+    /// Sets the severity `Kind` of the `MessageBox`.
+    #[must_use]
+    pub const fn with_kind(mut self, kind: Kind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the `Colors` of the `MessageBox`. Platforms without custom color
+    /// support ignore it.
+    #[must_use]
+    pub const fn with_colors(mut self, colors: Colors) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Appends a button identified by `id`.
+    #[must_use]
+    pub fn with_button(self, id: i32, text: &str) -> Self {
+        self.with_button_flags(0, id, text)
+    }
+
+    /// Appends a button identified by `id` that is pressed by the return key.
+    #[must_use]
+    pub fn with_return_button(self, id: i32, text: &str) -> Self {
+        self.with_button_flags(
+            sdl_messagebox::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT,
+            id,
+            text,
+        )
+    }
+
+    /// Appends a button identified by `id` that is pressed by the escape key.
+    #[must_use]
+    pub fn with_escape_button(self, id: i32, text: &str) -> Self {
+        self.with_button_flags(
+            sdl_messagebox::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT,
+            id,
+            text,
+        )
+    }
+
+    /// Shows the `MessageBox` and blocks until it is dismissed. Returns the id
+    /// of the pressed button, or `None` if the dialog was closed without
+    /// pressing one.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the `MessageBox` cannot be shown.
+    /// The `String` returned will contain details about the failure.
+    pub fn show(&self) -> Result<Option<i32>, String> {
+        let title =
+            CString::new(self.title.as_str()).map_err(|e| e.to_string())?;
+        let message =
+            CString::new(self.message.as_str()).map_err(|e| e.to_string())?;
+        let texts = self
+            .buttons
+            .iter()
+            .map(|button| CString::new(button.text.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        let buttons = self.button_data(&texts);
+        let color_scheme = self.colors.map(Colors::color_scheme);
+        let data = sdl_messagebox::SdlMessageBoxData {
+            flags: self.kind.flags(),
+            window: null_mut(),
+            title: title.as_ptr(),
+            message: message.as_ptr(),
+            num_buttons: c_int::try_from(buttons.len())
+                .map_err(|e| e.to_string())?,
+            buttons: buttons.as_ptr(),
+            color_scheme: color_scheme
+                .as_ref()
+                .map_or(ptr::null(), ptr::from_ref),
+        };
+        let mut button_id = -1;
+        if !unsafe {
+            sdl_messagebox::SDL_ShowMessageBox(
+                &raw const data,
+                &raw mut button_id,
+            )
+        } {
+            return Err(unsafe { CStr::from_ptr(sdl_error::SDL_GetError()) }
+                .to_string_lossy()
+                .to_string());
+        }
+        Ok((button_id != -1).then_some(button_id))
+    }
+
+    #[must_use]
+    fn with_button_flags(mut self, flags: c_uint, id: i32, text: &str) -> Self {
+        self.buttons.push(Button {
+            flags,
+            id,
+            text: text.to_owned(),
+        });
+        self
+    }
+
+    #[must_use]
+    fn button_data(
+        &self,
+        texts: &[CString],
+    ) -> Vec<sdl_messagebox::SdlMessageBoxButtonData> {
+        self.buttons
+            .iter()
+            .zip(texts)
+            .map(|(button, text)| sdl_messagebox::SdlMessageBoxButtonData {
+                flags: button.flags,
+                button_id: button.id,
+                text: text.as_ptr(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags() {
+        assert_eq!(Kind::Error.flags(), sdl_messagebox::SDL_MESSAGEBOX_ERROR);
+        assert_eq!(
+            Kind::Warning.flags(),
+            sdl_messagebox::SDL_MESSAGEBOX_WARNING
+        );
+        assert_eq!(
+            Kind::Information.flags(),
+            sdl_messagebox::SDL_MESSAGEBOX_INFORMATION
+        );
+    }
+
+    #[test]
+    fn color_scheme() {
+        let color_scheme = Colors {
+            background: (1, 2, 3),
+            text: (4, 5, 6),
+            button_border: (7, 8, 9),
+            button_background: (10, 11, 12),
+            button_selected: (13, 14, 15),
+        }
+        .color_scheme();
+        assert_eq!(
+            color_scheme.colors.map(|c| (c.r, c.g, c.b)),
+            [(1, 2, 3), (4, 5, 6), (7, 8, 9), (10, 11, 12), (13, 14, 15)]
+        );
+    }
+
+    #[test]
+    fn button_data() {
+        let message_box = MessageBox::new("title", "message")
+            .with_return_button(0, "yes")
+            .with_button(1, "maybe")
+            .with_escape_button(2, "no");
+        let texts = [c"yes".to_owned(), c"maybe".to_owned(), c"no".to_owned()];
+        let buttons = message_box.button_data(&texts);
+        assert_eq!(
+            buttons
+                .iter()
+                .map(|b| (b.flags, b.button_id))
+                .collect::<Vec<_>>(),
+            [
+                (sdl_messagebox::SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT, 0),
+                (0, 1),
+                (sdl_messagebox::SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT, 2),
+            ]
+        );
+        assert!(
+            buttons
+                .iter()
+                .zip(&texts)
+                .all(|(b, t)| ptr::eq(b.text, t.as_ptr()))
+        );
+    }
+
+    #[test]
+    fn show_invalid() {
+        assert!(MessageBox::new("title\0", "message").show().is_err());
+        assert!(MessageBox::new("title", "message\0").show().is_err());
+        assert!(
+            MessageBox::new("title", "message")
+                .with_button(0, "\0")
+                .show()
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[ignore = "requires user interaction"]
+    fn show() {
+        assert!(
+            MessageBox::new("title", "message")
+                .with_kind(Kind::Warning)
+                .with_return_button(0, "ok")
+                .show()
+                .is_ok()
+        );
+    }
+}
@@ -1,11 +1,24 @@
 pub use self::{
-    angle::{into_degs, into_rads},
+    angle::{FULL_TURN, HALF_TURN, QUARTER_TURN, into_degs, into_rads},
+    grid::{tile_to_world, world_to_tile},
     matrix::Matrix,
+    screen::{screen_to_world, world_to_screen},
     traits::{Angle, Position, Rectangle, Scale},
+    transform::Transform,
     vector::Vector,
 };
 
+/// `Flywheel` [easing] module.
+pub mod easing;
+
+/// `Flywheel` [lossless] module.
+#[cfg(feature = "lossless")]
+pub mod lossless;
+
 mod angle;
+mod grid;
 mod matrix;
+mod screen;
 mod traits;
+mod transform;
 mod vector;
@@ -1,5 +1,14 @@
 use core::f32::consts::PI;
 
+/// Quarter turn in radians.
+pub const QUARTER_TURN: f32 = PI / 2.0;
+
+/// Half turn in radians.
+pub const HALF_TURN: f32 = PI;
+
+/// Full turn in radians.
+pub const FULL_TURN: f32 = PI * 2.0;
+
 /// Converts degrees to radians.
 #[must_use]
 pub const fn into_rads(degs: f32) -> f32 {
@@ -16,6 +25,14 @@ pub const fn into_degs(rads: f32) -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn turns() {
+        approx::assert_relative_eq!(QUARTER_TURN, super::into_rads(90.0));
+        approx::assert_relative_eq!(HALF_TURN, super::into_rads(180.0));
+        approx::assert_relative_eq!(FULL_TURN, super::into_rads(360.0));
+        approx::assert_relative_eq!(FULL_TURN, core::f32::consts::TAU);
+    }
+
     #[test]
     fn into_rads() {
         const RADS: f32 = super::into_rads(0.0);
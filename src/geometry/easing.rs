@@ -0,0 +1,51 @@
+/// Eases in quadratically, starting slow and accelerating.
+#[must_use]
+pub const fn ease_in(t: f32) -> f32 {
+    t * t
+}
+
+/// Eases out quadratically, starting fast and decelerating.
+#[must_use]
+pub const fn ease_out(t: f32) -> f32 {
+    t * (2.0 - t)
+}
+
+/// Eases in and out cubically, starting and ending slow.
+#[must_use]
+pub const fn ease_in_out(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    const EASINGS: [fn(f32) -> f32; 3] =
+        [super::ease_in, super::ease_out, super::ease_in_out];
+
+    #[test]
+    fn endpoints() {
+        for easing in EASINGS {
+            approx::assert_relative_eq!(easing(0.0), 0.0);
+            approx::assert_relative_eq!(easing(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn monotonic() {
+        for easing in EASINGS {
+            let mut previous = easing(0.0);
+            for i in 1..=100_u8 {
+                let current = easing(f32::from(i) / 100.0);
+                assert!(previous < current);
+                previous = current;
+            }
+        }
+    }
+
+    #[test]
+    fn ease_in_out() {
+        const HALF: f32 = super::ease_in_out(0.5);
+        approx::assert_relative_eq!(HALF, 0.5);
+        assert!(super::ease_in_out(0.25) < super::ease_in(0.5));
+        assert!(super::ease_in_out(0.75) > super::ease_out(0.5));
+    }
+}
@@ -0,0 +1,66 @@
+use super::Vector;
+
+/// Converts a world position to the coordinates of the tile containing it.
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn world_to_tile(position: &Vector, tile_size: f32) -> (i32, i32) {
+    (
+        (position.x / tile_size).floor() as i32,
+        (position.y / tile_size).floor() as i32,
+    )
+}
+
+/// Converts tile coordinates to the world position of the tile's origin.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub const fn tile_to_world(tile: (i32, i32), tile_size: f32) -> Vector {
+    Vector::new(tile.0 as f32 * tile_size, tile.1 as f32 * tile_size, 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TILE_SIZE: f32 = 16.0;
+
+    #[test]
+    fn world_to_tile() {
+        assert_eq!(
+            super::world_to_tile(&Vector::new(0.0, 0.0, 6.0), TILE_SIZE),
+            (0, 0)
+        );
+        assert_eq!(
+            super::world_to_tile(&Vector::new(15.9, 16.0, 6.0), TILE_SIZE),
+            (0, 1)
+        );
+        assert_eq!(
+            super::world_to_tile(&Vector::new(-0.1, -16.0, 6.0), TILE_SIZE),
+            (-1, -1)
+        );
+        assert_eq!(
+            super::world_to_tile(&Vector::new(-16.1, 40.0, 6.0), TILE_SIZE),
+            (-2, 2)
+        );
+    }
+
+    #[test]
+    fn tile_to_world() {
+        assert_eq!(
+            super::tile_to_world((0, 0), TILE_SIZE),
+            Vector::new(0.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            super::tile_to_world((-2, 3), TILE_SIZE),
+            Vector::new(-32.0, 48.0, 0.0)
+        );
+        for tile in [(0, 0), (3, -5), (-7, 2), (-1, -1)] {
+            assert_eq!(
+                super::world_to_tile(
+                    &super::tile_to_world(tile, TILE_SIZE),
+                    TILE_SIZE
+                ),
+                tile
+            );
+        }
+    }
+}
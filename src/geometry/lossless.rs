@@ -0,0 +1,276 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Value that can be serialized as the bit patterns of its floats.
+pub trait Lossless: Sized {
+    /// Bit patterns of the floats of the value.
+    type Bits: Serialize + for<'de> Deserialize<'de>;
+
+    /// Converts the value into the bit patterns of its floats.
+    #[must_use]
+    fn to_bits(&self) -> Self::Bits;
+
+    /// Constructs a new value from the bit patterns of its floats.
+    #[must_use]
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+/// Serializes the value as the bit patterns of its floats, preserving
+/// subnormals, infinities, NaN payloads and signed zeros. Intended for
+/// `#[serde(with = "flywheel::geometry::lossless")]`.
+///
+/// # Errors
+///
+/// This function will return an error if the serializer fails.
+pub fn serialize<T: Lossless, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.to_bits().serialize(serializer)
+}
+
+/// Deserializes a value from the bit patterns of its floats written by
+/// `serialize`.
+///
+/// # Errors
+///
+/// This function will return an error if the deserializer fails.
+pub fn deserialize<'de, T: Lossless, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    T::Bits::deserialize(deserializer).map(T::from_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{f32::consts::PI, fmt::Display};
+
+    use serde::{
+        de::value::{Error, SeqDeserializer},
+        ser::{self, Error as _, Impossible},
+    };
+
+    use super::*;
+    use crate::geometry::{Matrix, Transform, Vector};
+
+    const SUBNORMAL: f32 = f32::from_bits(1);
+    const PRECISE: f32 = 0.1 + 0.2;
+
+    #[derive(Default)]
+    struct Bits(Vec<u32>);
+
+    macro_rules! unsupported {
+        ($($name:ident($($arg:ty),*) -> $ok:ty;)*) => {
+            $(
+                fn $name(self, $(_: $arg),*) -> Result<$ok, Error> {
+                    Err(Error::custom("unsupported"))
+                }
+            )*
+        };
+    }
+
+    impl Serializer for &mut Bits {
+        type Error = Error;
+        type Ok = ();
+        type SerializeMap = Impossible<(), Error>;
+        type SerializeSeq = Impossible<(), Error>;
+        type SerializeStruct = Self;
+        type SerializeStructVariant = Impossible<(), Error>;
+        type SerializeTuple = Self;
+        type SerializeTupleStruct = Impossible<(), Error>;
+        type SerializeTupleVariant = Impossible<(), Error>;
+
+        unsupported! {
+            serialize_bool(bool) -> ();
+            serialize_i8(i8) -> ();
+            serialize_i16(i16) -> ();
+            serialize_i32(i32) -> ();
+            serialize_i64(i64) -> ();
+            serialize_u8(u8) -> ();
+            serialize_u16(u16) -> ();
+            serialize_u64(u64) -> ();
+            serialize_f32(f32) -> ();
+            serialize_f64(f64) -> ();
+            serialize_char(char) -> ();
+            serialize_str(&str) -> ();
+            serialize_bytes(&[u8]) -> ();
+            serialize_none() -> ();
+            serialize_unit() -> ();
+            serialize_unit_struct(&'static str) -> ();
+            serialize_unit_variant(&'static str, u32, &'static str) -> ();
+            serialize_seq(Option<usize>) -> Self::SerializeSeq;
+            serialize_tuple_struct(&'static str, usize)
+                -> Self::SerializeTupleStruct;
+            serialize_tuple_variant(&'static str, u32, &'static str, usize)
+                -> Self::SerializeTupleVariant;
+            serialize_map(Option<usize>) -> Self::SerializeMap;
+            serialize_struct_variant(&'static str, u32, &'static str, usize)
+                -> Self::SerializeStructVariant;
+        }
+
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.0.push(v);
+            Ok(())
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self, Error> {
+            Ok(self)
+        }
+
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self, Error> {
+            Ok(self)
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(
+            self,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error::custom("unsupported"))
+        }
+
+        fn collect_str<T: ?Sized + Display>(self, _: &T) -> Result<(), Error> {
+            Err(Error::custom("unsupported"))
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error::custom("unsupported"))
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), Error> {
+            Err(Error::custom("unsupported"))
+        }
+    }
+
+    impl ser::SerializeTuple for &mut Bits {
+        type Error = Error;
+        type Ok = ();
+
+        fn serialize_element<T: ?Sized + Serialize>(
+            &mut self,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl ser::SerializeStruct for &mut Bits {
+        type Error = Error;
+        type Ok = ();
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            _: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(&mut **self)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Level {
+        #[serde(with = "super")]
+        spawn: Vector,
+        #[serde(with = "super")]
+        camera: Transform,
+    }
+
+    #[must_use]
+    fn round_trip<T: Lossless>(value: &T) -> T {
+        let mut bits = Bits::default();
+        serialize(value, &mut bits).unwrap();
+        deserialize(SeqDeserializer::<_, Error>::new(bits.0.into_iter()))
+            .unwrap()
+    }
+
+    #[test]
+    fn vector() {
+        for vector in [
+            Vector::new(SUBNORMAL, -SUBNORMAL, f32::MIN_POSITIVE / 2.0),
+            Vector::new(f32::INFINITY, f32::NEG_INFINITY, f32::NAN),
+            Vector::new(PRECISE, PI, -0.0),
+            Vector::new(f32::from_bits(0x7FC0_1234), f32::MAX, f32::MIN),
+        ] {
+            assert_eq!(round_trip(&vector).to_bits(), vector.to_bits());
+        }
+    }
+
+    #[test]
+    fn matrix() {
+        for matrix in [
+            Matrix::new(SUBNORMAL, f32::INFINITY),
+            Matrix::new(PRECISE, -0.0),
+            Matrix::new(PI / 3.0, f32::NAN),
+            Matrix::identity(),
+        ] {
+            assert_eq!(round_trip(&matrix).to_bits(), matrix.to_bits());
+        }
+    }
+
+    #[test]
+    fn transform() {
+        for transform in [
+            Transform::new(
+                Vector::new(SUBNORMAL, f32::NEG_INFINITY, PRECISE),
+                f32::NAN,
+                -0.0,
+            ),
+            Transform::new(Vector::new(PI, 1e-40, f32::MAX), PRECISE, 1e-45),
+        ] {
+            assert_eq!(round_trip(&transform).to_bits(), transform.to_bits());
+        }
+    }
+
+    #[test]
+    fn with() {
+        let level = Level {
+            spawn: Vector::new(SUBNORMAL, f32::NAN, PRECISE),
+            camera: Transform::new(Vector::new(-0.0, 1.0, 2.0), PI, 1e-40),
+        };
+        let mut bits = Bits::default();
+        level.serialize(&mut bits).unwrap();
+        assert_eq!(bits.0.len(), 8);
+        let camera = bits.0.split_off(3);
+        let loaded = Level::deserialize(SeqDeserializer::<_, Error>::new(
+            [bits.0, camera]
+                .into_iter()
+                .map(|bits| SeqDeserializer::new(bits.into_iter())),
+        ))
+        .unwrap();
+        assert_eq!(loaded.spawn.to_bits(), level.spawn.to_bits());
+        assert_eq!(loaded.camera.to_bits(), level.camera.to_bits());
+    }
+
+    #[test]
+    fn bits() {
+        let mut bits = Bits::default();
+        serialize(&Vector::new(SUBNORMAL, -0.0, 1.0), &mut bits).unwrap();
+        assert_eq!(bits.0, [1, 0x8000_0000, 0x3F80_0000]);
+        assert!(
+            deserialize::<Vector, _>(SeqDeserializer::<_, Error>::new(
+                [1_u32, 2].into_iter()
+            ))
+            .is_err()
+        );
+    }
+}
@@ -35,6 +35,13 @@ impl Matrix {
         }
     }
 
+    /// Constructs a new unscaled `Matrix` that rotates the X axis onto the 2D
+    /// direction of the given `Vector`, or an identity `Matrix` if it is zero.
+    #[must_use]
+    pub fn look_along(direction: &Vector) -> Self {
+        Self::new(direction.angle(), 1.0)
+    }
+
     /// Returns the transformed X component of a `Vector`.
     #[must_use]
     pub const fn transform_x(&self, vector: &Vector) -> f32 {
@@ -52,6 +59,32 @@ impl Matrix {
     pub const fn transform_z(&self, vector: &Vector) -> f32 {
         vector.z * self.scale
     }
+
+    /// Transforms the given `Vector`s in place.
+    pub fn transform_slice(&self, vectors: &mut [Vector]) {
+        for vector in vectors {
+            *vector = *self * *vector;
+        }
+    }
+}
+
+#[cfg(feature = "lossless")]
+impl super::lossless::Lossless for Matrix {
+    type Bits = [u32; 6];
+
+    fn to_bits(&self) -> Self::Bits {
+        let ((a, b), (c, d)) = self.columns;
+        [self.angle, self.scale, a, b, c, d].map(f32::to_bits)
+    }
+
+    fn from_bits(bits: Self::Bits) -> Self {
+        let [angle, scale, a, b, c, d] = bits.map(f32::from_bits);
+        Self {
+            angle,
+            scale,
+            columns: ((a, b), (c, d)),
+        }
+    }
 }
 
 impl Angle for Matrix {
@@ -109,6 +142,26 @@ mod tests {
         assert_eq!(MATRIX * VECTOR, VECTOR);
     }
 
+    #[test]
+    fn look_along() {
+        const UNIT_X: Vector = Vector::new(1.0, 0.0, 0.0);
+        for direction in [
+            Vector::new(3.0, 4.0, 6.0),
+            Vector::new(-2.0, 0.5, 0.0),
+            Vector::new(0.0, -7.0, -1.0),
+        ] {
+            let normalized = direction.normalize2();
+            let vector = Matrix::look_along(&direction) * UNIT_X;
+            approx::assert_relative_eq!(vector.x, normalized.x, epsilon = 1e-6);
+            approx::assert_relative_eq!(vector.y, normalized.y, epsilon = 1e-6);
+            approx::assert_relative_eq!(vector.z, 0.0);
+        }
+        assert_eq!(
+            Matrix::look_along(&Vector::new(0.0, 0.0, 6.0)),
+            Matrix::identity()
+        );
+    }
+
     #[test]
     fn transform_x() {
         const SCALE: f32 = 7.0;
@@ -193,6 +246,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transform_slice() {
+        const SCALE: f32 = 7.0;
+        let matrix = Matrix::new(geometry::into_rads(45.0), SCALE);
+        let vectors = [
+            Vector::new(2.0, 3.0, 6.0),
+            Vector::new(-2.0, 3.0, -6.0),
+            Vector::new(0.0, 0.0, 0.0),
+        ];
+        let mut transformed = vectors;
+        matrix.transform_slice(&mut transformed);
+        for (vector, transformed) in vectors.iter().zip(transformed) {
+            assert_eq!(matrix * *vector, transformed);
+        }
+    }
+
     #[test]
     fn set_angle() {
         const ANGLE: f32 = 45.0;
@@ -202,6 +271,18 @@ mod tests {
         approx::assert_relative_eq!(matrix.scale(), 1.0);
     }
 
+    #[test]
+    fn try_set_angle() {
+        const ANGLE: f32 = 45.0;
+        let mut matrix = Matrix::new(ANGLE, 2.0);
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert!(matrix.try_set_angle(value).is_err());
+            assert_eq!(matrix, Matrix::new(ANGLE, 2.0));
+        }
+        assert!(matrix.try_set_angle(-ANGLE).is_ok());
+        assert_eq!(matrix, Matrix::new(-ANGLE, 2.0));
+    }
+
     #[test]
     fn set_scale() {
         const SCALE: f32 = 7.0;
@@ -0,0 +1,79 @@
+use super::Vector;
+
+/// Converts a Y-up world position to a Y-down screen position.
+///
+/// The world origin maps to the bottom-left corner of a viewport with the
+/// given height, while the screen origin is its top-left corner.
+#[must_use]
+pub const fn world_to_screen(
+    position: &Vector,
+    viewport_height: f32,
+) -> Vector {
+    Vector::new(position.x, viewport_height - position.y, position.z)
+}
+
+/// Converts a Y-down screen position within a viewport of the given height to
+/// a Y-up world position. This is the inverse of [`world_to_screen`].
+#[must_use]
+pub const fn screen_to_world(
+    position: &Vector,
+    viewport_height: f32,
+) -> Vector {
+    Vector::new(position.x, viewport_height - position.y, position.z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT_HEIGHT: f32 = 720.0;
+
+    #[test]
+    fn world_to_screen() {
+        assert_eq!(
+            super::world_to_screen(
+                &Vector::new(0.0, 0.0, 6.0),
+                VIEWPORT_HEIGHT
+            ),
+            Vector::new(0.0, 720.0, 6.0)
+        );
+        assert_eq!(
+            super::world_to_screen(
+                &Vector::new(100.0, 720.0, 0.0),
+                VIEWPORT_HEIGHT
+            ),
+            Vector::new(100.0, 0.0, 0.0)
+        );
+        assert_eq!(
+            super::world_to_screen(
+                &Vector::new(-5.0, 200.0, 0.0),
+                VIEWPORT_HEIGHT
+            ),
+            Vector::new(-5.0, 520.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn screen_to_world() {
+        assert_eq!(
+            super::screen_to_world(
+                &Vector::new(100.0, 0.0, 0.0),
+                VIEWPORT_HEIGHT
+            ),
+            Vector::new(100.0, 720.0, 0.0)
+        );
+        for position in [
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(32.0, 500.0, 6.0),
+            Vector::new(-8.0, -16.0, 1.0),
+        ] {
+            assert_eq!(
+                super::screen_to_world(
+                    &super::world_to_screen(&position, VIEWPORT_HEIGHT),
+                    VIEWPORT_HEIGHT
+                ),
+                position
+            );
+        }
+    }
+}
@@ -16,6 +16,22 @@ pub trait Angle {
 
     /// Sets the angle of the object.
     fn set_angle(&mut self, value: f32);
+
+    /// Sets the angle of the object if it is finite.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error and leave the object unchanged if
+    /// the angle is `NaN` or infinite.
+    #[allow(clippy::result_unit_err)]
+    fn try_set_angle(&mut self, value: f32) -> Result<(), ()> {
+        if value.is_finite() {
+            self.set_angle(value);
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
 }
 
 /// Represents an object that can be scaled.
@@ -0,0 +1,342 @@
+use core::{
+    f32::consts::{PI, TAU},
+    ops::{AddAssign, Mul, SubAssign},
+};
+
+use super::{Angle, Matrix, Position, Scale, Vector};
+
+/// 2.5D `Transform` of position, rotation, scale.
+#[repr(C)]
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform {
+    /// Position of the `Transform`.
+    pub position: Vector,
+    /// Rotation of the `Transform`.
+    pub rotation: f32,
+    /// Scale of the `Transform`.
+    pub scale: f32,
+}
+
+impl Transform {
+    /// Constructs a new `Transform` from the given position, rotation, scale.
+    #[must_use]
+    pub const fn new(position: Vector, rotation: f32, scale: f32) -> Self {
+        Self {
+            position,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Constructs a new identity `Transform`.
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self::new(Vector::ZERO, 0.0, 1.0)
+    }
+
+    /// Constructs a new `Matrix` from the rotation, scale of the `Transform`.
+    #[must_use]
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new(self.rotation, self.scale)
+    }
+
+    /// Returns the transformed `Vector`, scaled and rotated first, then
+    /// translated by the position.
+    #[must_use]
+    pub fn transform(&self, vector: &Vector) -> Vector {
+        self.to_matrix() * *vector + self.position
+    }
+
+    /// Returns the transformed point, which is affected by the position.
+    #[must_use]
+    pub fn transform_point(&self, point: &Vector) -> Vector {
+        self.transform(point)
+    }
+
+    /// Returns the transformed direction, which is only scaled and rotated and
+    /// is not affected by the position.
+    #[must_use]
+    pub fn transform_direction(&self, direction: &Vector) -> Vector {
+        self.to_matrix() * *direction
+    }
+
+    /// Constructs a new `Transform` interpolated from the `Transform` towards
+    /// the target `Transform`, rotating along the shortest path.
+    #[must_use]
+    pub fn lerp(self, target: Self, t: f32) -> Self {
+        let delta = (target.rotation - self.rotation + PI).rem_euclid(TAU) - PI;
+        Self::new(
+            self.position.lerp(target.position, t),
+            delta.mul_add(t, self.rotation),
+            (target.scale - self.scale).mul_add(t, self.scale),
+        )
+    }
+
+    /// Constructs a new `Transform` by composing the `Transform` as a child of
+    /// the parent `Transform`.
+    #[must_use]
+    pub fn then(&self, parent: &Self) -> Self {
+        Self::new(
+            parent.transform(&self.position),
+            self.rotation + parent.rotation,
+            self.scale * parent.scale,
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(feature = "lossless")]
+impl super::lossless::Lossless for Transform {
+    type Bits = [u32; 5];
+
+    fn to_bits(&self) -> Self::Bits {
+        let Vector { x, y, z } = self.position;
+        [x, y, z, self.rotation, self.scale].map(f32::to_bits)
+    }
+
+    fn from_bits(bits: Self::Bits) -> Self {
+        let [x, y, z, rotation, scale] = bits.map(f32::from_bits);
+        Self::new(Vector::new(x, y, z), rotation, scale)
+    }
+}
+
+impl Position for Transform {
+    fn position(&self) -> &Vector {
+        &self.position
+    }
+
+    fn set_position(&mut self, value: &Vector) {
+        self.position = *value;
+    }
+}
+
+impl Angle for Transform {
+    fn angle(&self) -> f32 {
+        self.rotation
+    }
+
+    fn set_angle(&mut self, value: f32) {
+        self.rotation = value;
+    }
+}
+
+impl Scale for Transform {
+    fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    fn set_scale(&mut self, value: f32) {
+        self.scale = value;
+    }
+}
+
+/// Transforms the `Vector` as a point, use
+/// [`transform_direction`](Transform::transform_direction) for directions.
+impl Mul<Vector> for Transform {
+    type Output = Vector;
+
+    fn mul(self, rhs: Self::Output) -> Self::Output {
+        self.transform_point(&rhs)
+    }
+}
+
+impl AddAssign<Vector> for Transform {
+    fn add_assign(&mut self, rhs: Vector) {
+        self.position += rhs;
+    }
+}
+
+impl SubAssign<Vector> for Transform {
+    fn sub_assign(&mut self, rhs: Vector) {
+        self.position -= rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry;
+
+    #[test]
+    fn new() {
+        const POSITION: Vector = Vector::new(2.0, 3.0, 6.0);
+        const ROTATION: f32 = geometry::into_rads(45.0);
+        const SCALE: f32 = 7.0;
+        const TRANSFORM: Transform = Transform::new(POSITION, ROTATION, SCALE);
+        assert_eq!(TRANSFORM.position(), &POSITION);
+        approx::assert_relative_eq!(TRANSFORM.angle(), ROTATION);
+        approx::assert_relative_eq!(TRANSFORM.scale(), SCALE);
+    }
+
+    #[test]
+    fn identity() {
+        const TRANSFORM: Transform = Transform::identity();
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        assert_eq!(TRANSFORM.to_matrix(), Matrix::identity());
+        assert_eq!(TRANSFORM.transform(&VECTOR), VECTOR);
+    }
+
+    #[test]
+    fn to_matrix() {
+        const ROTATION: f32 = geometry::into_rads(45.0);
+        const SCALE: f32 = 7.0;
+        assert_eq!(
+            Transform::new(Vector::new(2.0, 3.0, 6.0), ROTATION, SCALE)
+                .to_matrix(),
+            Matrix::new(ROTATION, SCALE)
+        );
+    }
+
+    #[test]
+    fn transform() {
+        let transform = Transform::new(
+            Vector::new(2.0, 3.0, 6.0),
+            geometry::into_rads(90.0),
+            2.0,
+        );
+        let vector = transform.transform(&Vector::new(1.0, 0.0, 1.0));
+        approx::assert_relative_eq!(vector.x, 2.0);
+        approx::assert_relative_eq!(vector.y, 5.0);
+        approx::assert_relative_eq!(vector.z, 8.0);
+    }
+
+    #[test]
+    fn transform_point_direction() {
+        const VECTOR: Vector = Vector::new(1.0, 0.0, 1.0);
+        let transform = Transform::new(
+            Vector::new(2.0, 3.0, 6.0),
+            geometry::into_rads(90.0),
+            2.0,
+        );
+        let point = transform.transform_point(&VECTOR);
+        approx::assert_relative_eq!(point.x, 2.0);
+        approx::assert_relative_eq!(point.y, 5.0);
+        approx::assert_relative_eq!(point.z, 8.0);
+        assert_eq!(transform * VECTOR, point);
+        let direction = transform.transform_direction(&VECTOR);
+        approx::assert_relative_eq!(direction.x, 0.0, epsilon = 1e-6);
+        approx::assert_relative_eq!(direction.y, 2.0);
+        approx::assert_relative_eq!(direction.z, 2.0);
+        assert_eq!(point - direction, transform.position);
+        let mut moved = transform;
+        moved += Vector::new(5.0, 5.0, 5.0);
+        assert_eq!(moved.transform_direction(&VECTOR), direction);
+        assert_ne!(moved.transform_point(&VECTOR), point);
+    }
+
+    #[test]
+    fn lerp() {
+        let transform = Transform::new(
+            Vector::new(0.0, 0.0, 0.0),
+            geometry::into_rads(170.0),
+            1.0,
+        );
+        let target = Transform::new(
+            Vector::new(2.0, 4.0, 6.0),
+            geometry::into_rads(-170.0),
+            3.0,
+        );
+        assert_eq!(transform.lerp(target, 0.0), transform);
+        let lerped = transform.lerp(target, 0.5);
+        assert_eq!(lerped.position, Vector::new(1.0, 2.0, 3.0));
+        approx::assert_relative_eq!(
+            lerped.rotation,
+            geometry::into_rads(180.0)
+        );
+        approx::assert_relative_eq!(lerped.scale, 2.0);
+        let lerped = transform.lerp(target, 1.0);
+        assert_eq!(lerped.position, target.position);
+        approx::assert_relative_eq!(
+            lerped.rotation,
+            geometry::into_rads(190.0)
+        );
+        approx::assert_relative_eq!(lerped.scale, target.scale);
+    }
+
+    #[test]
+    fn then() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        let parent = Transform::new(
+            Vector::new(5.0, -1.0, 2.0),
+            geometry::into_rads(90.0),
+            2.0,
+        );
+        let child = Transform::new(
+            Vector::new(1.0, 0.0, 1.0),
+            geometry::into_rads(45.0),
+            3.0,
+        );
+        let composed = child.then(&parent);
+        approx::assert_relative_eq!(
+            composed.rotation,
+            geometry::into_rads(135.0)
+        );
+        approx::assert_relative_eq!(composed.scale, 6.0);
+        let expected = parent.transform(&child.transform(&VECTOR));
+        let vector = composed.transform(&VECTOR);
+        approx::assert_relative_eq!(vector.x, expected.x, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.y, expected.y, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.z, expected.z, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Transform::default(), Transform::identity());
+    }
+
+    #[test]
+    fn set_position() {
+        const POSITION: Vector = Vector::new(2.0, 3.0, 6.0);
+        let mut transform = Transform::identity();
+        transform.set_position(&POSITION);
+        assert_eq!(transform.position(), &POSITION);
+        approx::assert_relative_eq!(transform.angle(), 0.0);
+        approx::assert_relative_eq!(transform.scale(), 1.0);
+    }
+
+    #[test]
+    fn set_angle() {
+        const ANGLE: f32 = 45.0;
+        let mut transform = Transform::identity();
+        transform.set_angle(ANGLE);
+        approx::assert_relative_eq!(transform.angle(), ANGLE);
+        approx::assert_relative_eq!(transform.scale(), 1.0);
+    }
+
+    #[test]
+    fn set_scale() {
+        const SCALE: f32 = 7.0;
+        let mut transform = Transform::identity();
+        transform.set_scale(SCALE);
+        approx::assert_relative_eq!(transform.scale(), SCALE);
+        approx::assert_relative_eq!(transform.angle(), 0.0);
+    }
+
+    #[test]
+    fn add_assign() {
+        const ROTATION: f32 = geometry::into_rads(45.0);
+        let mut transform =
+            Transform::new(Vector::new(2.0, 3.0, 6.0), ROTATION, 7.0);
+        transform += Vector::new(1.0, -1.0, 0.5);
+        assert_eq!(transform.position, Vector::new(3.0, 2.0, 6.5));
+        approx::assert_relative_eq!(transform.rotation, ROTATION);
+        approx::assert_relative_eq!(transform.scale, 7.0);
+    }
+
+    #[test]
+    fn sub_assign() {
+        const ROTATION: f32 = geometry::into_rads(45.0);
+        let mut transform =
+            Transform::new(Vector::new(2.0, 3.0, 6.0), ROTATION, 7.0);
+        transform -= Vector::new(1.0, -1.0, 0.5);
+        assert_eq!(transform.position, Vector::new(1.0, 4.0, 5.5));
+        approx::assert_relative_eq!(transform.rotation, ROTATION);
+        approx::assert_relative_eq!(transform.scale, 7.0);
+    }
+}
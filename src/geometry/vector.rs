@@ -18,6 +18,11 @@ pub struct Vector {
 }
 
 impl Vector {
+    /// `Vector` with all components set to one.
+    pub const ONE: Self = Self::new(1.0, 1.0, 1.0);
+    /// `Vector` with all components set to zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
     /// Constructs a new `Vector` from the given X, Y, Z components.
     #[must_use]
     pub const fn new(x: f32, y: f32, z: f32) -> Self {
@@ -31,6 +36,27 @@ impl Vector {
         Self::new(angle.cos() * magnitude2, angle.sin() * magnitude2, z)
     }
 
+    /// Constructs a new `Vector` from the `Vector` with the given X component.
+    #[must_use]
+    pub const fn with_x(mut self, x: f32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// Constructs a new `Vector` from the `Vector` with the given Y component.
+    #[must_use]
+    pub const fn with_y(mut self, y: f32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// Constructs a new `Vector` from the `Vector` with the given Z component.
+    #[must_use]
+    pub const fn with_z(mut self, z: f32) -> Self {
+        self.z = z;
+        self
+    }
+
     /// Calculates the 2D magnitude of the `Vector`.
     #[must_use]
     pub fn magnitude2(&self) -> f32 {
@@ -43,6 +69,48 @@ impl Vector {
         self.magnitude2().hypot(self.z)
     }
 
+    /// Calculates the squared 2D magnitude of the `Vector`.
+    #[must_use]
+    pub const fn magnitude2_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Calculates the squared 3D magnitude of the `Vector`.
+    #[must_use]
+    pub const fn magnitude3_squared(&self) -> f32 {
+        self.magnitude2_squared() + self.z * self.z
+    }
+
+    /// Calculates the 2D dot product of the `Vector` and the other `Vector`.
+    #[must_use]
+    pub const fn dot2(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Calculates the 3D dot product of the `Vector` and the other `Vector`.
+    #[must_use]
+    pub const fn dot3(&self, other: &Self) -> f32 {
+        self.dot2(other) + self.z * other.z
+    }
+
+    /// Calculates the 2D perp dot product of the `Vector` and the other
+    /// `Vector`, which is positive if the other `Vector` is counterclockwise.
+    #[must_use]
+    pub const fn cross2(&self, other: &Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Constructs a new `Vector` from the 3D cross product of the `Vector` and
+    /// the other `Vector`.
+    #[must_use]
+    pub const fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.cross2(other),
+        )
+    }
+
     /// Constructs a new 2D unit `Vector` from the `Vector`.
     #[must_use]
     pub fn normalize2(mut self) -> Self {
@@ -71,6 +139,131 @@ impl Vector {
         }
         self
     }
+
+    /// Calculates the squared 2D distance between the `Vector` and the other
+    /// `Vector`.
+    #[must_use]
+    pub const fn distance2_squared(&self, other: &Self) -> f32 {
+        let x = self.x - other.x;
+        let y = self.y - other.y;
+        x * x + y * y
+    }
+
+    /// Returns whether each component of the `Vector` is within the given
+    /// epsilon of the other `Vector`.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+
+    /// Calculates the signed 2D angle from the `Vector` to the other `Vector`,
+    /// where counterclockwise is positive.
+    #[must_use]
+    pub fn signed_angle_to(&self, other: &Self) -> f32 {
+        self.cross2(other).atan2(self.dot2(other))
+    }
+
+    /// Constructs a new `Vector` smoothed from the `Vector` towards the target
+    /// `Vector` by the given rate over the given delta time, independently of
+    /// the framerate.
+    #[must_use]
+    pub fn smooth_damp(self, target: Self, rate: f32, dt: f32) -> Self {
+        self + (target - self) * (1.0 - (-rate * dt).exp())
+    }
+
+    /// Constructs a new `Vector` linearly interpolated from the `Vector`
+    /// towards the target `Vector`.
+    ///
+    /// `t` is not clamped, values outside of `0.0..=1.0` extrapolate.
+    #[must_use]
+    pub fn lerp(self, target: Self, t: f32) -> Self {
+        self + (target - self) * t
+    }
+
+    /// Constructs a new `Vector` interpolated from the `Vector` towards the
+    /// target `Vector` with the given easing function applied to `t`.
+    #[must_use]
+    pub fn ease_to(self, target: Self, t: f32, easing: fn(f32) -> f32) -> Self {
+        self.lerp(target, easing(t))
+    }
+
+    /// Constructs a new `Vector` by rotating the `Vector` around the pivot
+    /// `Vector` by the given angle on the XY plane, preserving the Z component.
+    #[must_use]
+    pub fn rotate_around(self, pivot: Self, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let x = self.x - pivot.x;
+        let y = self.y - pivot.y;
+        Self::new(
+            x.mul_add(cos, -y * sin) + pivot.x,
+            x.mul_add(sin, y * cos) + pivot.y,
+            self.z,
+        )
+    }
+
+    /// Constructs a new `Vector` by mirroring the `Vector` across the line
+    /// through the origin spanned by the axis `Vector` on the XY plane,
+    /// preserving the Z component. A zero axis leaves the `Vector` unchanged.
+    #[must_use]
+    pub fn mirror_across(self, axis: Self) -> Self {
+        if axis.x == 0.0 && axis.y == 0.0 {
+            return self;
+        }
+        let axis = axis.normalize2();
+        let projection = self.x.mul_add(axis.x, self.y * axis.y) * 2.0;
+        Self::new(
+            projection.mul_add(axis.x, -self.x),
+            projection.mul_add(axis.y, -self.y),
+            self.z,
+        )
+    }
+
+    /// Constructs a new `Vector` with each component rounded down.
+    #[must_use]
+    pub const fn floor(self) -> Self {
+        Self::new(self.x.floor(), self.y.floor(), self.z.floor())
+    }
+
+    /// Constructs a new `Vector` with each component rounded up.
+    #[must_use]
+    pub const fn ceil(self) -> Self {
+        Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+    }
+
+    /// Constructs a new `Vector` with each component rounded to the nearest
+    /// integer, rounding half-way cases away from zero.
+    #[must_use]
+    pub const fn round(self) -> Self {
+        Self::new(self.x.round(), self.y.round(), self.z.round())
+    }
+
+    /// Constructs a new `Vector` with the fractional part of each component
+    /// removed.
+    #[must_use]
+    pub const fn trunc(self) -> Self {
+        Self::new(self.x.trunc(), self.y.trunc(), self.z.trunc())
+    }
+}
+
+impl Default for Vector {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(feature = "lossless")]
+impl super::lossless::Lossless for Vector {
+    type Bits = [u32; 3];
+
+    fn to_bits(&self) -> Self::Bits {
+        [self.x.to_bits(), self.y.to_bits(), self.z.to_bits()]
+    }
+
+    fn from_bits([x, y, z]: Self::Bits) -> Self {
+        Self::new(f32::from_bits(x), f32::from_bits(y), f32::from_bits(z))
+    }
 }
 
 impl Angle for Vector {
@@ -165,7 +358,7 @@ impl DivAssign<f32> for Vector {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::geometry;
+    use crate::geometry::{self, easing};
 
     #[test]
     fn new() {
@@ -205,6 +398,16 @@ mod tests {
         approx::assert_relative_eq!(vector.magnitude3(), MAGNITUDE3);
     }
 
+    #[test]
+    fn with() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        const WITH_X: Vector = VECTOR.with_x(-1.0);
+        assert_eq!(WITH_X, Vector::new(-1.0, 3.0, 6.0));
+        assert_eq!(VECTOR.with_y(-1.0), Vector::new(2.0, -1.0, 6.0));
+        assert_eq!(VECTOR.with_z(0.0), Vector::new(2.0, 3.0, 0.0));
+        assert_eq!(VECTOR.with_x(7.0).with_z(8.0), Vector::new(7.0, 3.0, 8.0));
+    }
+
     #[test]
     fn magnitude2() {
         approx::assert_relative_eq!(
@@ -221,6 +424,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn magnitude_squared() {
+        for vector in [
+            Vector::new(2.0, 3.0, 6.0),
+            Vector::new(-1.5, 0.25, -4.0),
+            Vector::new(0.0, 0.0, 0.0),
+        ] {
+            approx::assert_relative_eq!(
+                vector.magnitude2_squared(),
+                vector.magnitude2().powi(2),
+                epsilon = 1e-5
+            );
+            approx::assert_relative_eq!(
+                vector.magnitude3_squared(),
+                vector.magnitude3().powi(2),
+                epsilon = 1e-5
+            );
+        }
+        approx::assert_relative_eq!(
+            Vector::new(2.0, 3.0, 6.0).magnitude3_squared(),
+            49.0
+        );
+    }
+
+    #[test]
+    fn dot() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        let orthogonal = Vector::new(-3.0, 2.0, 0.0);
+        approx::assert_relative_eq!(VECTOR.dot2(&orthogonal), 0.0);
+        approx::assert_relative_eq!(VECTOR.dot3(&orthogonal), 0.0);
+        approx::assert_relative_eq!(
+            VECTOR.dot2(&(VECTOR * 2.0)),
+            VECTOR.magnitude2_squared() * 2.0
+        );
+        approx::assert_relative_eq!(
+            VECTOR.dot3(&VECTOR),
+            VECTOR.magnitude3_squared()
+        );
+        let other = Vector::new(-1.5, 0.5, -4.0);
+        approx::assert_relative_eq!(VECTOR.dot2(&other), -1.5);
+        approx::assert_relative_eq!(VECTOR.dot3(&other), -25.5);
+        approx::assert_relative_eq!(other.dot3(&VECTOR), VECTOR.dot3(&other));
+    }
+
+    #[test]
+    fn cross() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        const OTHER: Vector = Vector::new(-1.5, 0.5, -4.0);
+        assert_eq!(VECTOR.cross(&OTHER), Vector::new(-15.0, -1.0, 5.5));
+        assert_eq!(VECTOR.cross(&OTHER), -OTHER.cross(&VECTOR));
+        approx::assert_relative_eq!(VECTOR.cross(&OTHER).dot3(&VECTOR), 0.0);
+        approx::assert_relative_eq!(VECTOR.cross(&OTHER).dot3(&OTHER), 0.0);
+        assert_eq!(VECTOR.cross(&(VECTOR * -2.0)), Vector::new(0.0, 0.0, 0.0));
+        assert_eq!(
+            Vector::new(1.0, 0.0, 0.0).cross(&Vector::new(0.0, 1.0, 0.0)),
+            Vector::new(0.0, 0.0, 1.0)
+        );
+        approx::assert_relative_eq!(VECTOR.cross2(&OTHER), 5.5);
+        approx::assert_relative_eq!(OTHER.cross2(&VECTOR), -5.5);
+        approx::assert_relative_eq!(VECTOR.cross2(&(VECTOR * 3.0)), 0.0);
+    }
+
     #[test]
     fn normalize2() {
         let vector = Vector::new(0.0, 0.0, 6.0);
@@ -253,6 +518,164 @@ mod tests {
         );
     }
 
+    #[test]
+    fn distance2_squared() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        const OTHER: Vector = Vector::new(5.0, -1.0, -4.0);
+        approx::assert_relative_eq!(VECTOR.distance2_squared(&OTHER), 25.0);
+        approx::assert_relative_eq!(
+            OTHER.distance2_squared(&VECTOR),
+            (VECTOR - OTHER).magnitude2_squared()
+        );
+        approx::assert_relative_eq!(VECTOR.distance2_squared(&VECTOR), 0.0);
+    }
+
+    #[test]
+    fn approx_eq() {
+        const EPSILON: f32 = 0.25;
+        let vector = Vector::new(2.0, 3.0, 6.0);
+        assert!(vector.approx_eq(&vector, 0.0));
+        assert!(vector.approx_eq(&Vector::new(2.25, 2.75, 6.0), EPSILON));
+        assert!(!vector.approx_eq(&Vector::new(2.5, 3.0, 6.0), EPSILON));
+        assert!(!vector.approx_eq(&Vector::new(2.0, 2.5, 6.0), EPSILON));
+        assert!(!vector.approx_eq(&Vector::new(2.0, 3.0, 6.5), EPSILON));
+    }
+
+    #[test]
+    fn signed_angle_to() {
+        let vector = Vector::new(2.0, 3.0, 6.0);
+        let mut other = vector;
+        approx::assert_relative_eq!(vector.signed_angle_to(&other), 0.0);
+        other.set_angle(vector.angle() + geometry::into_rads(90.0));
+        approx::assert_relative_eq!(
+            vector.signed_angle_to(&other),
+            geometry::into_rads(90.0)
+        );
+        other.set_angle(vector.angle() - geometry::into_rads(90.0));
+        approx::assert_relative_eq!(
+            vector.signed_angle_to(&other),
+            geometry::into_rads(-90.0)
+        );
+        other.set_angle(vector.angle() + geometry::into_rads(135.0));
+        approx::assert_relative_eq!(
+            vector.signed_angle_to(&other),
+            geometry::into_rads(135.0)
+        );
+    }
+
+    #[test]
+    fn smooth_damp() {
+        const TARGET: Vector = Vector::new(2.0, 3.0, 6.0);
+        const RATE: f32 = 5.0;
+        const DT: f32 = 0.1;
+        let mut vector = Vector::new(0.0, 0.0, 0.0);
+        let mut distance = (TARGET - vector).magnitude3();
+        for _ in 0..10 {
+            vector = vector.smooth_damp(TARGET, RATE, DT);
+            assert!((TARGET - vector).magnitude3() < distance);
+            distance = (TARGET - vector).magnitude3();
+        }
+        let mut halved = Vector::new(0.0, 0.0, 0.0);
+        for _ in 0..20 {
+            halved = halved.smooth_damp(TARGET, RATE, DT / 2.0);
+        }
+        approx::assert_relative_eq!(vector.x, halved.x, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.y, halved.y, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.z, halved.z, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn lerp() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        const TARGET: Vector = Vector::new(-2.0, 5.0, 10.0);
+        assert_eq!(VECTOR.lerp(TARGET, 0.0), VECTOR);
+        assert_eq!(VECTOR.lerp(TARGET, 1.0), TARGET);
+        assert_eq!(VECTOR.lerp(TARGET, 0.5), Vector::new(0.0, 4.0, 8.0));
+        assert_eq!(VECTOR.lerp(TARGET, 1.5), Vector::new(-4.0, 6.0, 12.0));
+        assert_eq!(VECTOR.lerp(TARGET, -0.5), Vector::new(4.0, 2.0, 4.0));
+    }
+
+    #[test]
+    fn ease_to() {
+        const TARGET: Vector = Vector::new(2.0, 4.0, 6.0);
+        let vector = Vector::new(0.0, 0.0, 0.0);
+        assert_eq!(vector.ease_to(TARGET, 0.0, easing::ease_in), vector);
+        assert_eq!(vector.ease_to(TARGET, 1.0, easing::ease_in), TARGET);
+        assert_eq!(
+            vector.ease_to(TARGET, 0.5, easing::ease_in),
+            Vector::new(0.5, 1.0, 1.5)
+        );
+        assert_ne!(
+            vector.ease_to(TARGET, 0.5, easing::ease_out),
+            vector + (TARGET - vector) * 0.5
+        );
+    }
+
+    #[test]
+    fn rotate_around() {
+        const PIVOT: Vector = Vector::new(2.0, 3.0, -4.0);
+        let vector = Vector::new(4.0, 3.0, 6.0)
+            .rotate_around(PIVOT, geometry::into_rads(90.0));
+        approx::assert_relative_eq!(vector.x, 2.0, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.y, 5.0, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.z, 6.0);
+        let vector = vector.rotate_around(PIVOT, geometry::into_rads(-180.0));
+        approx::assert_relative_eq!(vector.x, 2.0, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.y, 1.0, epsilon = 1e-5);
+        approx::assert_relative_eq!(vector.z, 6.0);
+        assert_eq!(PIVOT.rotate_around(PIVOT, 1.0), PIVOT);
+    }
+
+    #[test]
+    fn mirror_across() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        assert_eq!(
+            VECTOR.mirror_across(Vector::new(5.0, 0.0, 1.0)),
+            Vector::new(2.0, -3.0, 6.0)
+        );
+        assert_eq!(
+            VECTOR.mirror_across(Vector::new(0.0, -1.0, 0.0)),
+            Vector::new(-2.0, 3.0, 6.0)
+        );
+        let vector = VECTOR.mirror_across(Vector::new(1.0, 1.0, 0.0));
+        approx::assert_relative_eq!(vector.x, 3.0, epsilon = 1e-6);
+        approx::assert_relative_eq!(vector.y, 2.0, epsilon = 1e-6);
+        approx::assert_relative_eq!(vector.z, 6.0);
+        assert_eq!(VECTOR.mirror_across(Vector::new(0.0, 0.0, 1.0)), VECTOR);
+    }
+
+    #[test]
+    fn rounding() {
+        const VECTOR: Vector = Vector::new(1.5, -1.5, -0.4);
+        assert_eq!(VECTOR.floor(), Vector::new(1.0, -2.0, -1.0));
+        assert_eq!(VECTOR.ceil(), Vector::new(2.0, -1.0, -0.0));
+        assert_eq!(VECTOR.round(), Vector::new(2.0, -2.0, -0.0));
+        assert_eq!(VECTOR.trunc(), Vector::new(1.0, -1.0, -0.0));
+        for vector in [VECTOR, Vector::new(-2.7, 3.2, 0.5)] {
+            for (rounded, scalar) in [
+                (vector.floor(), f32::floor as fn(f32) -> f32),
+                (vector.ceil(), f32::ceil),
+                (vector.round(), f32::round),
+                (vector.trunc(), f32::trunc),
+            ] {
+                assert_eq!(
+                    rounded,
+                    Vector::new(
+                        scalar(vector.x),
+                        scalar(vector.y),
+                        scalar(vector.z)
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Vector::default(), Vector::ZERO);
+        assert_eq!(Vector::ONE, Vector::new(1.0, 1.0, 1.0));
+    }
+
     #[test]
     fn angle() {
         approx::assert_relative_eq!(Vector::new(0.0, 0.0, 0.0).angle(), 0.0);
@@ -301,6 +724,19 @@ mod tests {
         approx::assert_relative_eq!(vector.magnitude3(), MAGNITUDE3);
     }
 
+    #[test]
+    fn try_set_angle() {
+        const VECTOR: Vector = Vector::new(2.0, 3.0, 6.0);
+        let mut vector = VECTOR;
+        assert!(vector.try_set_angle(f32::NAN).is_err());
+        assert!(vector.try_set_angle(f32::INFINITY).is_err());
+        assert_eq!(vector, VECTOR);
+        let angle = geometry::into_rads(90.0);
+        assert!(vector.try_set_angle(angle).is_ok());
+        approx::assert_relative_eq!(vector.angle(), angle);
+        approx::assert_relative_eq!(vector.z, VECTOR.z);
+    }
+
     #[test]
     fn neg() {
         const X: f32 = 2.0;
@@ -1,5 +1,6 @@
-#![feature(extern_types, negative_impls, trait_alias)]
-#![deny(warnings)]
+#![feature(map_try_insert, trait_alias)]
+#![cfg_attr(feature = "engine", feature(extern_types, negative_impls))]
+#![cfg_attr(feature = "strict", deny(warnings))]
 #![warn(clippy::cargo, clippy::nursery, clippy::pedantic, missing_docs)]
 
 //! # Flywheel Engine
@@ -9,11 +10,15 @@
 //! simplifies game development while leveraging `SDL3`'s power for smooth
 //! rendering and input handling.
 
+#[cfg(feature = "engine")]
+pub use self::engine::{Context, ContextData};
 pub use self::{
-    engine::{Context, ContextData},
     geometry::{
-        Angle, Matrix, Position, Rectangle, Scale, Vector, into_degs, into_rads,
+        Angle, FULL_TURN, HALF_TURN, Matrix, Position, QUARTER_TURN, Rectangle,
+        Scale, Transform, Vector, into_degs, into_rads, screen_to_world,
+        tile_to_world, world_to_screen, world_to_tile,
     },
+    random::Rng,
 };
 
 /// `Flywheel` [ecs] module.
@@ -27,3 +32,7 @@ pub mod engine;
 /// `Flywheel` [geometry] module.
 #[forbid(unsafe_code)]
 pub mod geometry;
+
+/// `Flywheel` [random] module.
+#[forbid(unsafe_code)]
+pub mod random;
@@ -0,0 +1,3 @@
+pub use self::rng::Rng;
+
+mod rng;
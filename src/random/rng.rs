@@ -0,0 +1,100 @@
+const MULTIPLIER: u64 = 0x5851_F42D_4C95_7F2D;
+const INCREMENT: u64 = 0xDA3E_39CB_94B9_5BDB;
+
+/// Deterministic pseudo-random number generator (PCG32).
+///
+/// The same seed always produces the same sequence, which makes it suitable
+/// for reproducible gameplay and replays.
+///
+/// # Examples
+///
+/// ```
+/// use flywheel::Rng;
+///
+/// let mut rng = Rng::seeded(42);
+/// let value = rng.range(-1.0, 1.0);
+/// assert!((-1.0..1.0).contains(&value));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Constructs a new `Rng` from the given seed.
+    #[must_use]
+    pub const fn seeded(seed: u64) -> Self {
+        let mut rng = Self { state: 0 };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// Generates the next random `u32`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.step();
+        ((((state >> 18) ^ state) >> 27) as u32)
+            .rotate_right((state >> 59) as u32)
+    }
+
+    /// Generates the next random `f32` in the range [0, 1).
+    pub const fn next_f32(&mut self) -> f32 {
+        f32::from_bits(0x3F80_0000 | (self.next_u32() >> 9)) - 1.0
+    }
+
+    /// Generates the next random `f32` in the range [min, max).
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        (max - min).mul_add(self.next_f32(), min)
+    }
+
+    const fn step(&mut self) {
+        self.state =
+            self.state.wrapping_mul(MULTIPLIER).wrapping_add(INCREMENT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 42;
+
+    #[test]
+    fn seeded() {
+        assert_eq!(Rng::seeded(SEED), Rng::seeded(SEED));
+        assert_ne!(Rng::seeded(SEED), Rng::seeded(SEED + 1));
+    }
+
+    #[test]
+    fn next_u32() {
+        let mut rng = Rng::seeded(SEED);
+        let mut other = Rng::seeded(SEED);
+        for _ in 0..100 {
+            assert_eq!(rng.next_u32(), other.next_u32());
+        }
+        let mut other = Rng::seeded(SEED + 1);
+        assert!((0..100).any(|_| rng.next_u32() != other.next_u32()));
+    }
+
+    #[test]
+    fn next_f32() {
+        let mut rng = Rng::seeded(SEED);
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&rng.next_f32()));
+        }
+    }
+
+    #[test]
+    fn range() {
+        const MIN: f32 = -3.0;
+        const MAX: f32 = 7.0;
+        let mut rng = Rng::seeded(SEED);
+        for _ in 0..1000 {
+            assert!((MIN..MAX).contains(&rng.range(MIN, MAX)));
+        }
+    }
+}
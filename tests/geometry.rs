@@ -0,0 +1,13 @@
+use flywheel::{Matrix, Transform, Vector, into_rads, world_to_tile};
+
+#[test]
+fn geometry() {
+    let transform =
+        Transform::new(Vector::new(2.0, 3.0, 6.0), into_rads(90.0), 2.0);
+    let vector = transform.transform(&Vector::new(1.0, 0.0, 1.0));
+    approx::assert_relative_eq!(vector.x, 2.0);
+    approx::assert_relative_eq!(vector.y, 5.0);
+    approx::assert_relative_eq!(vector.z, 8.0);
+    assert_eq!(Matrix::identity() * vector, vector);
+    assert_eq!(world_to_tile(&vector, 4.0), (0, 1));
+}